@@ -0,0 +1,40 @@
+// > 레이드 카탈로그 버전 확인
+// ? 프로그램에 포함된 카탈로그(src-tauri/data/default_raids.json)와 원격 카탈로그(raids.json)는 같은 버전 번호를 공유
+// ? 프로그램은 적용된 버전보다 높은 카탈로그만 적용하므로, 새 카탈로그는 어느 쪽이든 이미 배포된 번호보다 커야 함
+// ? 사용법:
+// ?   npm run raids:check -- app <배포된 raids.json 경로 또는 URL>     (프로그램 릴리즈 전: 포함 카탈로그 > 원격)
+// ?   npm run raids:check -- remote <새 raids.json 경로 또는 URL>      (원격 배포 전: 새 원격 > 포함 카탈로그)
+
+import { readFileSync } from "node:fs";
+
+const BUNDLED_PATH = "src-tauri/data/default_raids.json";
+
+const [mode, manifestSource] = process.argv.slice(2);
+if (!["app", "remote"].includes(mode) || !manifestSource) {
+    console.error("사용법: npm run raids:check -- <app|remote> <raids.json 경로 또는 URL>");
+    process.exit(1);
+}
+
+// + 경로 또는 URL에서 JSON 읽기
+async function readJson(source) {
+    if (/^https?:\/\//.test(source)) {
+        const response = await fetch(source);
+        if (!response.ok) throw new Error(`${source} 요청 실패: ${response.status}`);
+        return response.json();
+    }
+    return JSON.parse(readFileSync(source, "utf8"));
+}
+
+const bundled = JSON.parse(readFileSync(BUNDLED_PATH, "utf8")).version;
+const remote = (await readJson(manifestSource)).version;
+
+const [newer, older, label] =
+    mode === "app" ? [bundled, remote, "포함 카탈로그"] : [remote, bundled, "원격 카탈로그"];
+
+if (!Number.isInteger(newer) || !Number.isInteger(older) || newer <= older) {
+    console.error(`❌ ${label} 버전(v${newer})이 v${older}보다 높아야 합니다 (포함: v${bundled}, 원격: v${remote})`);
+    process.exit(1);
+}
+console.log(`✅ 레이드 카탈로그 버전 확인 완료 (포함: v${bundled}, 원격: v${remote})`);
+
+// > 레이드 카탈로그 버전 확인
@@ -0,0 +1,32 @@
+// > 릴리즈 서명
+// ? latest.json의 bootstrapper, release 파일을 minisign으로 서명하고 signature 필드를 채운 뒤 latest.json도 서명
+// ? 프로그램은 서명이 없는 latest.json을 거부하므로 릴리즈마다 latest.json, latest.json.minisig를 함께 업로드해야 함
+// ? 사용법: npm run release:sign -- <bootstrapper.exe> <LoaAssist.exe> [minisign 비밀키 경로]
+
+import { execFileSync } from "node:child_process";
+import { readFileSync, writeFileSync } from "node:fs";
+
+const [bootstrapperPath, releasePath, secretKey] = process.argv.slice(2);
+if (!bootstrapperPath || !releasePath) {
+    console.error("사용법: npm run release:sign -- <bootstrapper.exe> <LoaAssist.exe> [minisign 비밀키 경로]");
+    process.exit(1);
+}
+
+// + minisign으로 파일 서명 후 .minisig 내용 반환
+function sign(path) {
+    const args = ["-S", "-m", path];
+    if (secretKey) args.push("-s", secretKey);
+    execFileSync("minisign", args, { stdio: "inherit" });
+    return readFileSync(`${path}.minisig`, "utf8");
+}
+
+const latest = JSON.parse(readFileSync("latest.json", "utf8"));
+latest.bootstrapper.signature = sign(bootstrapperPath);
+latest.release.signature = sign(releasePath);
+writeFileSync("latest.json", JSON.stringify(latest, null, 4) + "\n");
+
+// ? signature 필드를 채운 뒤에 latest.json 서명 (latest.json.minisig)
+sign("latest.json");
+console.log("✅ latest.json, latest.json.minisig 생성 완료");
+
+// > 릴리즈 서명
@@ -0,0 +1,208 @@
+use super::dock_manager::is_auto_hidden;
+use crate::state::persist::mark_dirty;
+use crate::state::store::{get_state, subscribe_settings};
+use crate::state::types::{AnchorCorner, FocusSettings, WindowAnchor};
+use crate::window_utils::window_search::find_game_window;
+use crate::window_utils::window_system::{window_system, Rect, WindowId};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{PhysicalPosition, WebviewWindow};
+
+// > 게임 창 따라가기
+// ? 오버레이 위치를 게임 창 내용 영역의 가장 가까운 모서리 기준으로 저장하고, 게임 창이 움직이면 같이 이동
+// ? 게임 창은 Auto Focus와 같은 창 이름 비교(find_game_window)로 찾음
+
+/// ? 게임 창 위치 확인 주기
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(100);
+/// ? 게임 창을 찾지 못했을 때 다시 찾기까지 간격 (창 목록 조회 부담)
+const SEARCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// ? 마지막으로 확인한 게임 창 (창 id, 내용 영역)
+static GAME_CLIENT: Mutex<Option<(WindowId, Rect)>> = Mutex::new(None);
+/// ? 따라가기 위해 직접 옮긴 오버레이 위치 (사용자 이동과 구분)
+static LAST_FOLLOW: Mutex<Option<(i32, i32)>> = Mutex::new(None);
+
+/// + 사각형의 모서리 좌표
+fn corner_point(corner: AnchorCorner, rect: &Rect) -> (i32, i32) {
+    match corner {
+        AnchorCorner::TopLeft => (rect.x, rect.y),
+        AnchorCorner::TopRight => (rect.right(), rect.y),
+        AnchorCorner::BottomLeft => (rect.x, rect.bottom()),
+        AnchorCorner::BottomRight => (rect.right(), rect.bottom()),
+    }
+}
+
+/// + 오버레이와 가장 가까운 게임 창 모서리 기준 위치 계산
+pub fn anchor_for(overlay: &Rect, client: &Rect) -> WindowAnchor {
+    let (overlay_x, overlay_y) = overlay.center();
+    let (client_x, client_y) = client.center();
+
+    let corner = match (overlay_x < client_x, overlay_y < client_y) {
+        (true, true) => AnchorCorner::TopLeft,
+        (false, true) => AnchorCorner::TopRight,
+        (true, false) => AnchorCorner::BottomLeft,
+        (false, false) => AnchorCorner::BottomRight,
+    };
+
+    // ? 오버레이도 같은 모서리 기준 (창을 접어 크기가 바뀌어도 모서리에 붙어 있도록)
+    let (corner_x, corner_y) = corner_point(corner, client);
+    let (edge_x, edge_y) = corner_point(corner, overlay);
+
+    WindowAnchor {
+        corner,
+        offset_x: edge_x - corner_x,
+        offset_y: edge_y - corner_y,
+    }
+}
+
+/// + 게임 창 내용 영역과 기준 위치로 오버레이 위치 계산
+pub fn anchored_position(
+    anchor: &WindowAnchor,
+    client: &Rect,
+    overlay_size: (u32, u32),
+) -> (i32, i32) {
+    let (corner_x, corner_y) = corner_point(anchor.corner, client);
+    let (edge_x, edge_y) = corner_point(
+        anchor.corner,
+        &Rect::new(0, 0, overlay_size.0, overlay_size.1),
+    );
+
+    (
+        corner_x + anchor.offset_x - edge_x,
+        corner_y + anchor.offset_y - edge_y,
+    )
+}
+
+/// + 게임 창 내용 영역 찾기 (이전에 찾은 창이 있으면 그 창부터 확인)
+// ? 최소화된 게임 창은 위치가 의미 없으므로 이전 영역 유지
+fn locate_game_client(
+    cached: Option<(WindowId, Rect)>,
+    settings: &FocusSettings,
+) -> Option<(WindowId, Rect)> {
+    let system = window_system();
+
+    if let Some((id, previous)) = cached {
+        if system.is_minimized(id) {
+            return Some((id, previous));
+        }
+        if let Some(rect) = system.client_rect(id) {
+            return Some((id, rect));
+        }
+    }
+
+    let window = find_game_window(settings).ok().flatten()?;
+    if system.is_minimized(window.id) {
+        return None;
+    }
+    system.client_rect(window.id).map(|rect| (window.id, rect))
+}
+
+/// + 기준 위치 저장 (변경된 경우만)
+async fn save_anchor(anchor: WindowAnchor) {
+    let mut app_state = get_state().lock().await;
+    if app_state.window_anchor != Some(anchor) {
+        app_state.window_anchor = Some(anchor);
+        mark_dirty();
+    }
+}
+
+/// + 사용자가 오버레이를 옮겼을 때 게임 창 기준 위치 다시 저장
+pub async fn update_anchor(overlay: &Rect) {
+    if *LAST_FOLLOW.lock().unwrap() == Some((overlay.x, overlay.y)) {
+        return; // ? 게임 창을 따라가며 직접 옮긴 위치
+    }
+    let Some((_, client)) = *GAME_CLIENT.lock().unwrap() else {
+        return; // ? 게임 창을 아직 찾지 못함
+    };
+
+    save_anchor(anchor_for(overlay, &client)).await;
+}
+
+/// + 게임 창 따라가기 시작 (설정이 꺼져 있으면 설정이 바뀔 때까지 대기)
+pub fn start_anchor_follower(window: WebviewWindow) {
+    tauri::async_runtime::spawn(async move {
+        let mut next_search = Instant::now();
+        let mut settings_changed = subscribe_settings();
+
+        loop {
+            tokio::time::sleep(FOLLOW_INTERVAL).await;
+
+            let (enabled, focus_settings, anchor) = {
+                let app_state = get_state().lock().await;
+                let settings = &app_state.user_settings;
+                (
+                    settings.anchor_enabled,
+                    settings.auto_focus_settings.clone(),
+                    app_state.window_anchor,
+                )
+            };
+            if !enabled {
+                *GAME_CLIENT.lock().unwrap() = None;
+                if settings_changed.changed().await.is_err() {
+                    return;
+                }
+                continue;
+            }
+
+            // ? 오버레이가 숨겨져 있으면 다시 표시된 뒤 위치 맞춤
+            if window.is_minimized().unwrap_or(false) || !window.is_visible().unwrap_or(true) {
+                continue;
+            }
+            // ? 자동 숨기기로 가장자리 밖에 있는 동안 옮기면 숨긴 위치가 풀리므로 다시 표시된 뒤 맞춤
+            if is_auto_hidden() {
+                continue;
+            }
+
+            let cached = *GAME_CLIENT.lock().unwrap();
+            if cached.is_none() && Instant::now() < next_search {
+                continue;
+            }
+
+            // ? 창 목록, 창 위치 조회는 블로킹 호출이라 별도 스레드에서 실행
+            let located = tauri::async_runtime::spawn_blocking(move || {
+                locate_game_client(cached, &focus_settings)
+            })
+            .await
+            .ok()
+            .flatten();
+
+            let Some(client) = located else {
+                if cached.is_some() {
+                    println!("⚓ 게임 창을 찾을 수 없어 따라가기 대기");
+                }
+                *GAME_CLIENT.lock().unwrap() = None;
+                next_search = Instant::now() + SEARCH_INTERVAL;
+                continue;
+            };
+
+            *GAME_CLIENT.lock().unwrap() = Some(client);
+            if cached == Some(client) {
+                continue; // ? 게임 창 위치, 크기 변화 없음
+            }
+
+            let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+                continue;
+            };
+            let (_, client_rect) = client;
+
+            match anchor {
+                // ? 처음 게임 창을 찾았으면 현재 위치를 기준으로 저장
+                None => {
+                    let overlay = Rect::new(position.x, position.y, size.width, size.height);
+                    save_anchor(anchor_for(&overlay, &client_rect)).await;
+                    println!("⚓ 게임 창 기준 위치 저장");
+                }
+                Some(anchor) => {
+                    let (x, y) =
+                        anchored_position(&anchor, &client_rect, (size.width, size.height));
+                    if (x, y) != (position.x, position.y) {
+                        *LAST_FOLLOW.lock().unwrap() = Some((x, y));
+                        let _ = window.set_position(PhysicalPosition::new(x, y));
+                    }
+                }
+            }
+        }
+    });
+}
+
+// > 게임 창 따라가기
@@ -0,0 +1,103 @@
+use super::dock_manager::DockManager;
+use crate::state::store::{get_state, subscribe_settings};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tauri::WebviewWindow;
+use tokio::sync::watch;
+
+// > 도킹 자동 숨기기
+// ? 도킹된 창 밖에 마우스가 dock_settings.hide_delay 동안 머물면 가장자리 밖으로 밀어 reveal_strip만 남김
+// ? 남은 부분에 마우스가 닿거나 자동 숨기기를 끄면 다시 표시 (마우스 위치는 auto_focus_shift 추적 스레드가 전달)
+
+/// ? 마우스가 창 안에 있는지 여부 (추적 스레드 → 자동 숨기기 작업)
+static POINTER_INSIDE: OnceLock<watch::Sender<bool>> = OnceLock::new();
+
+/// ? 대기시간이 0이거나 자동 숨기기가 꺼져 있을 때 다시 확인하기까지 최소 간격
+const MIN_IDLE: Duration = Duration::from_secs(1);
+
+fn pointer_channel() -> &'static watch::Sender<bool> {
+    POINTER_INSIDE.get_or_init(|| watch::channel(false).0)
+}
+
+/// + 마우스가 창 안으로 들어오거나 밖으로 나갔을 때 호출
+pub fn set_pointer_inside(inside: bool) {
+    pointer_channel().send_replace(inside);
+}
+
+/// + 자동 숨기기 설정 (사용 여부, 대기시간, 남길 두께)
+async fn auto_hide_settings() -> (bool, Duration, u32) {
+    let app_state = get_state().lock().await;
+    let settings = &app_state.user_settings;
+    (
+        settings.dock_settings.auto_hide,
+        Duration::from_secs(settings.dock_settings.hide_delay).max(MIN_IDLE),
+        settings.dock_settings.reveal_strip,
+    )
+}
+
+/// + 자동 숨기기 시작 (setup에서 1회 실행)
+pub fn start_auto_hide(window: WebviewWindow, dock_manager: Arc<DockManager>) {
+    tauri::async_runtime::spawn(async move {
+        let mut pointer = pointer_channel().subscribe();
+        let mut settings = subscribe_settings();
+
+        loop {
+            // ? 마우스가 창 밖으로 나갈 때까지 대기
+            while *pointer.borrow_and_update() {
+                if pointer.changed().await.is_err() {
+                    return;
+                }
+            }
+
+            // ? 대기시간 안에 마우스가 다시 들어오거나 설정이 바뀌면 처음부터 다시 대기
+            let (enabled, hide_delay, reveal_strip) = auto_hide_settings().await;
+            tokio::select! {
+                changed = pointer.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                changed = settings.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                _ = tokio::time::sleep(hide_delay) => {}
+            }
+
+            // ? 자동 숨기기가 꺼져 있거나 도킹되지 않았으면 다음 대기시간 후 다시 확인
+            if !enabled || !dock_manager.hide(&window, reveal_strip).await {
+                continue;
+            }
+            println!("🙈 도킹된 창 자동 숨기기");
+
+            // ? 남은 부분에 마우스가 닿거나 숨긴 상태에서 자동 숨기기를 끄면 다시 표시
+            loop {
+                tokio::select! {
+                    changed = pointer.changed() => {
+                        if changed.is_err() {
+                            return;
+                        }
+                        if *pointer.borrow_and_update() {
+                            break;
+                        }
+                    }
+                    changed = settings.changed() => {
+                        if changed.is_err() {
+                            return;
+                        }
+                        if !auto_hide_settings().await.0 {
+                            break;
+                        }
+                    }
+                }
+            }
+            dock_manager.reveal(&window).await;
+            println!("🙉 자동 숨기기 창 다시 표시");
+        }
+    });
+}
+
+// > 도킹 자동 숨기기
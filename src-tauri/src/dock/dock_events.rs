@@ -1,23 +1,32 @@
 // ? dock_manager는 같은 폴더에 있기 때문에 super(상대경로)로 처리
 use super::dock_manager::DockManager;
+use crate::window_utils::window_system::window_system;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::{PhysicalPosition, WebviewWindow, WindowEvent};
-use tokio::sync::Mutex;
+use tokio::sync::Notify;
 
 static MOVING: AtomicBool = AtomicBool::new(false);
 
+/// ? 디스플레이 변경 알림을 받을 수 없는 플랫폼의 확인 주기
+const DISPLAY_POLL: Duration = Duration::from_secs(2);
+
+/// ? 변경 알림 후 반영까지 대기 시간 (모니터 정보가 바뀌는 동안 연속으로 오는 알림을 하나로 합침)
+const DISPLAY_SETTLE: Duration = Duration::from_millis(500);
+
 pub fn setup_window_events(window: &WebviewWindow, dock_manager: Arc<DockManager>) {
     let window_clone = window.clone();
+    let display_changed = Arc::new(Notify::new());
 
-    let screen_size = match window_clone.primary_monitor() {
-        Ok(Some(monitor)) => Arc::new(Mutex::new(monitor.size().clone())),
-        _ => return,
-    };
+    start_display_watcher(
+        window.clone(),
+        Arc::clone(&dock_manager),
+        Arc::clone(&display_changed),
+    );
 
-    let screen_size_clone = Arc::clone(&screen_size);
-    window.on_window_event(move |event| {
-        if let WindowEvent::Moved(position) = *event {
+    window.on_window_event(move |event| match *event {
+        WindowEvent::Moved(position) => {
             // ? 연속 호출 방지: true면 return
             if MOVING.swap(true, Ordering::Relaxed) {
                 println!("연속 호출 방지");
@@ -28,19 +37,17 @@ pub fn setup_window_events(window: &WebviewWindow, dock_manager: Arc<DockManager
 
             let dock_manager = Arc::clone(&dock_manager);
             let window_clone = window_clone.clone();
-            let screen_size_clone = Arc::clone(&screen_size_clone);
 
             let position = PhysicalPosition::new(position.x, position.y);
 
             tauri::async_runtime::spawn(async move {
-                let screen_size = *screen_size_clone.lock().await;
                 let size = (
                     window_clone.outer_size().unwrap().width,
                     window_clone.outer_size().unwrap().height,
                 );
 
                 dock_manager
-                    .handle_window_move(position, size, screen_size, &window_clone)
+                    .handle_window_move(position, size, &window_clone)
                     .await;
 
                 // ? 창 이동이 완료된 후 다시 false로 변경 (지연 시간 없이)
@@ -50,5 +57,50 @@ pub fn setup_window_events(window: &WebviewWindow, dock_manager: Arc<DockManager
             let duration = start.elapsed();
             println!("Window was moved! Function execution took: {:?}", duration);
         }
+        WindowEvent::ScaleFactorChanged { .. } => {
+            // ? 배율이 다른 모니터로 옮겼거나 배율 설정이 바뀜
+            display_changed.notify_one();
+        }
+        _ => {}
+    });
+}
+
+/// + 디스플레이 구성 변경 감시 (모니터 추가/제거, 해상도, 배율, 작업 표시줄 위치)
+// ? Windows: WM_DISPLAYCHANGE, WM_SETTINGCHANGE 알림 / 그 외: DISPLAY_POLL 주기 확인
+fn start_display_watcher(
+    window: WebviewWindow,
+    dock_manager: Arc<DockManager>,
+    changed: Arc<Notify>,
+) {
+    let notify = Arc::clone(&changed);
+    let subscription = window_system().watch_display(Box::new(move || notify.notify_one()));
+
+    if subscription.is_none() {
+        let changed = Arc::clone(&changed);
+        tauri::async_runtime::spawn(async move {
+            loop {
+                tokio::time::sleep(DISPLAY_POLL).await;
+                changed.notify_one();
+            }
+        });
+    }
+
+    tauri::async_runtime::spawn(async move {
+        let _subscription = subscription; // ? 앱이 끝날 때까지 구독 유지
+        dock_manager.refresh_monitors(&window).await;
+        dock_manager.ensure_visible(&window).await;
+        dock_manager.restore(&window).await; // ? 모니터 배치를 알게 된 뒤 저장된 도킹 상태 적용
+
+        loop {
+            changed.notified().await;
+            tokio::time::sleep(DISPLAY_SETTLE).await;
+
+            if dock_manager.refresh_monitors(&window).await {
+                println!("🖥️ 디스플레이 구성이 변경되어 도킹 위치를 다시 계산합니다.");
+                dock_manager.reveal(&window).await; // ? 숨긴 위치는 이전 배치 기준
+                dock_manager.ensure_visible(&window).await;
+                dock_manager.redock(&window).await; // ? 바뀐 작업 영역 기준으로 다시 도킹
+            }
+        }
     });
 }
@@ -1,74 +1,246 @@
-use std::sync::Arc;
-use tauri::{PhysicalPosition, PhysicalSize, WebviewWindow};
+use super::anchor::update_anchor;
+use super::geometry::{hidden_position, snap_window, DockSides, SnapConfig};
+use super::monitor::{monitor_areas, monitor_for_rect, MonitorArea};
+use crate::state::persist::mark_dirty;
+use crate::state::store::get_state;
+use crate::state::types::DockState;
+use crate::ui::animations::{animate_window_move, ease_in_out_quad};
+use crate::window_utils::window_system::Rect;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{Emitter, PhysicalPosition, WebviewWindow};
 use tokio::sync::Mutex;
 
+/// ? 도킹 상태가 바뀌었을 때 Frontend로 보내는 이벤트 (payload: DockState)
+pub const DOCK_CHANGED_EVENT: &str = "dock://changed";
+
+/// ? 자동 숨기기 슬라이드 시간 (ms)
+const SLIDE_DURATION_MS: u64 = 250;
+
+/// ? 자동 숨기기로 가장자리 밖에 있는 상태 (슬라이드 중 포함)
+static AUTO_HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// + 자동 숨기기 상태인지 확인 (숨긴 위치는 도킹 계산, 위치 저장에서 제외)
+pub fn is_auto_hidden() -> bool {
+    AUTO_HIDDEN.load(Ordering::SeqCst)
+}
+
 pub struct DockManager {
-    docked: Arc<Mutex<bool>>,
-    threshold: i32,
+    sides: Mutex<DockSides>,                              // 현재 도킹된 가장자리
+    monitors: Mutex<Vec<MonitorArea>>,                    // 마지막으로 확인한 모니터 배치
+    shown_position: Mutex<Option<PhysicalPosition<i32>>>, // 자동 숨기기 전 위치
 }
 
 impl DockManager {
     pub fn new() -> Self {
         Self {
-            docked: Arc::new(Mutex::new(false)),
-            threshold: 5, // * 도킹 감지 거리
+            sides: Mutex::new(DockSides::default()),
+            monitors: Mutex::new(Vec::new()),
+            shown_position: Mutex::new(None),
+        }
+    }
+
+    /// + 모니터 배치 다시 확인 (변경되었으면 true)
+    pub async fn refresh_monitors(&self, window: &WebviewWindow) -> bool {
+        let areas = monitor_areas(window);
+        if areas.is_empty() {
+            return false; // ? 정보를 가져오지 못했으면 이전 배치 유지
+        }
+
+        let mut monitors = self.monitors.lock().await;
+        if *monitors == areas {
+            return false;
         }
+        *monitors = areas;
+        true
+    }
+
+    /// + 창이 있는 모니터 (모니터 정보가 없으면 None)
+    async fn monitor_for(&self, window_rect: &Rect) -> Option<MonitorArea> {
+        let monitors = self.monitors.lock().await;
+        monitor_for_rect(&monitors, window_rect).cloned()
     }
 
     pub async fn handle_window_move(
         &self,
         position: PhysicalPosition<i32>,
         size: (u32, u32),
-        screen_size: PhysicalSize<u32>,
         window: &WebviewWindow,
     ) {
-        let mut docked = self.docked.lock().await;
-        let mut new_position = None;
-
-        let right_edge = screen_size.width as i32 - size.0 as i32;
-        let bottom_edge = screen_size.height as i32 - size.1 as i32;
-
-        let near_left = position.x <= self.threshold;
-        let near_right = position.x >= right_edge - self.threshold;
-        let near_top = position.y <= self.threshold;
-        let near_bottom = position.y >= bottom_edge - self.threshold;
-
-        if near_left && near_top && !*docked {
-            // ? 왼쪽 상단
-            new_position = Some(PhysicalPosition::new(0, 0));
-        } else if near_right && near_top && !*docked {
-            // ? 오른쪽 상단
-            new_position = Some(PhysicalPosition::new(right_edge, 0));
-        } else if near_left && near_bottom && !*docked {
-            // ? 왼쪽 하단
-            new_position = Some(PhysicalPosition::new(0, bottom_edge));
-        } else if near_right && near_bottom && !*docked {
-            // ? 오른쪽 하단
-            new_position = Some(PhysicalPosition::new(right_edge, bottom_edge));
-        } else if near_left && !*docked {
-            // ? 왼쪽
-            new_position = Some(PhysicalPosition::new(0, position.y));
-        } else if near_right && !*docked {
-            // ? 오른쪽
-            new_position = Some(PhysicalPosition::new(right_edge, position.y));
-        } else if near_top && !*docked {
-            // ? 상단
-            new_position = Some(PhysicalPosition::new(position.x, 0));
-        } else if near_bottom && !*docked {
-            // ? 하단
-            new_position = Some(PhysicalPosition::new(position.x, bottom_edge));
+        if is_auto_hidden() {
+            return; // ? 자동 숨기기 슬라이드로 인한 이동은 도킹 해제로 보지 않음
         }
 
-        if let Some(pos) = new_position {
-            let current_position = window.outer_position().unwrap();
+        let window_rect = Rect::new(position.x, position.y, size.0, size.1);
 
-            // ? 현재 위치와 비교하여 변화가 있는 경우만 이동
-            if (current_position.x - pos.x).abs() > 1 || (current_position.y - pos.y).abs() > 1 {
-                *docked = true;
-                let _ = window.set_position(pos);
-            }
-        } else {
-            *docked = false;
+        let (anchor_enabled, settings) = {
+            let app_state = get_state().lock().await;
+            let settings = &app_state.user_settings;
+            (settings.anchor_enabled, settings.dock_settings.clone())
+        };
+
+        // ? 게임 창 따라가기 중에는 화면 가장자리 대신 게임 창 기준 위치 저장
+        if anchor_enabled {
+            *self.sides.lock().await = DockSides::default();
+            update_dock_state(DockState::default(), window).await;
+            update_anchor(&window_rect).await;
+            return;
+        }
+
+        let Some(monitor) = self.monitor_for(&window_rect).await else {
+            return;
+        };
+
+        // ? 설정 거리는 논리 픽셀이므로 모니터 배율에 맞춰 변환
+        let config = SnapConfig {
+            snap_distance: monitor.scale(settings.snap_distance),
+            undock_distance: monitor.scale(settings.undock_distance),
+            edges: settings.edges,
+        };
+
+        // ? 현재 모니터의 작업 영역 기준 (작업 표시줄 제외)
+        let snap = {
+            let mut sides = self.sides.lock().await;
+            let snap = snap_window(&window_rect, &monitor.work_area, *sides, &config);
+            *sides = snap.sides;
+            snap
+        };
+
+        // ? 현재 위치와 비교하여 변화가 있는 경우만 이동
+        if (position.x - snap.x).abs() > 1 || (position.y - snap.y).abs() > 1 {
+            let _ = window.set_position(PhysicalPosition::new(snap.x, snap.y));
         }
+
+        let dock_state = DockState {
+            position: snap.sides.position(),
+            monitor_id: snap.sides.is_docked().then(|| monitor.id.clone()),
+        };
+        update_dock_state(dock_state, window).await;
+    }
+
+    /// + 저장된 도킹 상태로 도킹 가장자리 복원 후 현재 위치 기준으로 다시 계산 (시작 시)
+    // ? 복원하지 않으면 창을 움직이기 전까지 자유 이동 상태로 보여 자동 숨기기가 동작하지 않음
+    pub async fn restore(&self, window: &WebviewWindow) {
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+            return;
+        };
+
+        let saved = get_state().lock().await.dock_state.position;
+        *self.sides.lock().await = DockSides::from_position(saved);
+        self.handle_window_move(position, (size.width, size.height), window)
+            .await;
     }
+
+    /// + 현재 위치에서 도킹 다시 계산 (디스플레이 구성 변경 후)
+    pub async fn redock(&self, window: &WebviewWindow) {
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+            return;
+        };
+
+        *self.sides.lock().await = DockSides::default(); // ? 이전 작업 영역 기준 도킹 상태 초기화
+        self.handle_window_move(position, (size.width, size.height), window)
+            .await;
+    }
+
+    /// + 창이 어느 모니터 작업 영역에도 보이지 않으면 가장 가까운 모니터 안으로 이동
+    // ? 모니터 연결 해제, 해상도 변경 후 창이 화면 밖에 남는 경우
+    pub async fn ensure_visible(&self, window: &WebviewWindow) {
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+            return;
+        };
+        let window_rect = Rect::new(position.x, position.y, size.width, size.height);
+
+        let monitors = self.monitors.lock().await;
+        let visible = monitors
+            .iter()
+            .any(|monitor| monitor.work_area.overlap_area(&window_rect) > 0);
+        if visible {
+            return;
+        }
+
+        let Some(monitor) = monitor_for_rect(&monitors, &window_rect) else {
+            return;
+        };
+        let area = monitor.work_area;
+        let x = position
+            .x
+            .clamp(area.x, (area.right() - size.width as i32).max(area.x));
+        let y = position
+            .y
+            .clamp(area.y, (area.bottom() - size.height as i32).max(area.y));
+
+        println!("🖥️ 창이 화면 밖에 있어 '{}' 모니터로 이동", monitor.id);
+        let _ = window.set_position(PhysicalPosition::new(x, y));
+    }
+
+    /// + 도킹된 가장자리 밖으로 창 숨기기 (숨겼으면 true)
+    pub async fn hide(&self, window: &WebviewWindow, reveal_strip: u32) -> bool {
+        if is_auto_hidden() {
+            return false;
+        }
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+            return false;
+        };
+        let window_rect = Rect::new(position.x, position.y, size.width, size.height);
+
+        let sides = *self.sides.lock().await;
+        let monitors = self.monitors.lock().await.clone();
+        let Some(monitor) = monitor_for_rect(&monitors, &window_rect) else {
+            return false;
+        };
+
+        let strip = monitor.scale(reveal_strip).max(1);
+        let Some((x, y)) = hidden_position(&window_rect, &monitor.work_area, sides, strip) else {
+            return false; // ? 도킹되지 않은 상태
+        };
+
+        // ? 숨긴 창이 다른 모니터나 작업 표시줄 위에 걸치면 그대로 보이므로 숨기지 않음
+        let hidden_rect = Rect::new(x, y, size.width, size.height);
+        let visible_area: u64 = monitors
+            .iter()
+            .map(|monitor| monitor.bounds.overlap_area(&hidden_rect))
+            .sum();
+        if visible_area > monitor.work_area.overlap_area(&hidden_rect) {
+            return false;
+        }
+
+        *self.shown_position.lock().await = Some(position);
+        AUTO_HIDDEN.store(true, Ordering::SeqCst);
+        animate_window_move(
+            window,
+            PhysicalPosition::new(x, y),
+            SLIDE_DURATION_MS,
+            ease_in_out_quad,
+        )
+        .await;
+        true
+    }
+
+    /// + 자동 숨기기 전 위치로 창 다시 표시
+    pub async fn reveal(&self, window: &WebviewWindow) {
+        let Some(position) = self.shown_position.lock().await.take() else {
+            return;
+        };
+
+        animate_window_move(window, position, SLIDE_DURATION_MS, ease_in_out_quad).await;
+        AUTO_HIDDEN.store(false, Ordering::SeqCst);
+    }
+}
+
+/// + 도킹 상태 저장 (변경된 경우만 저장하고 dock://changed 이벤트 발생)
+async fn update_dock_state(dock_state: DockState, window: &WebviewWindow) {
+    {
+        let mut app_state = get_state().lock().await;
+        if app_state.dock_state == dock_state {
+            return;
+        }
+        app_state.dock_state = dock_state.clone();
+        mark_dirty();
+    }
+
+    println!(
+        "📌 도킹 상태 변경: {:?} ({})",
+        dock_state.position,
+        dock_state.monitor_id.as_deref().unwrap_or("-")
+    );
+    let _ = window.emit(DOCK_CHANGED_EVENT, dock_state);
 }
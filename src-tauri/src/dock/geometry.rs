@@ -0,0 +1,548 @@
+use crate::state::types::{DockEdges, DockPosition};
+use crate::window_utils::window_system::Rect;
+
+// > 도킹 위치 계산
+// ? 창 이동과 분리된 순수 계산 (창 영역, 작업 영역, 이전 도킹 상태 → 새 위치, 도킹 상태)
+// ? 가로(왼쪽/오른쪽), 세로(위/아래)를 각각 계산하고 둘 다 도킹되면 모서리
+
+/// * 가로 도킹 방향
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HorizontalSide {
+    Left,
+    Right,
+}
+
+/// * 세로 도킹 방향
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerticalSide {
+    Top,
+    Bottom,
+}
+
+/// * 도킹된 가장자리 (둘 다 None이면 자유 이동 상태)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct DockSides {
+    pub horizontal: Option<HorizontalSide>,
+    pub vertical: Option<VerticalSide>,
+}
+
+impl DockSides {
+    pub fn is_docked(&self) -> bool {
+        self.horizontal.is_some() || self.vertical.is_some()
+    }
+
+    /// + 저장된 도킹 위치에서 복원
+    pub fn from_position(position: DockPosition) -> Self {
+        use HorizontalSide::{Left, Right};
+        use VerticalSide::{Bottom, Top};
+
+        let (horizontal, vertical) = match position {
+            DockPosition::Floating => (None, None),
+            DockPosition::Left => (Some(Left), None),
+            DockPosition::Right => (Some(Right), None),
+            DockPosition::Top => (None, Some(Top)),
+            DockPosition::Bottom => (None, Some(Bottom)),
+            DockPosition::TopLeft => (Some(Left), Some(Top)),
+            DockPosition::TopRight => (Some(Right), Some(Top)),
+            DockPosition::BottomLeft => (Some(Left), Some(Bottom)),
+            DockPosition::BottomRight => (Some(Right), Some(Bottom)),
+        };
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+
+    /// + 저장, 이벤트용 도킹 위치로 변환
+    pub fn position(&self) -> DockPosition {
+        match (self.horizontal, self.vertical) {
+            (None, None) => DockPosition::Floating,
+            (Some(HorizontalSide::Left), None) => DockPosition::Left,
+            (Some(HorizontalSide::Right), None) => DockPosition::Right,
+            (None, Some(VerticalSide::Top)) => DockPosition::Top,
+            (None, Some(VerticalSide::Bottom)) => DockPosition::Bottom,
+            (Some(HorizontalSide::Left), Some(VerticalSide::Top)) => DockPosition::TopLeft,
+            (Some(HorizontalSide::Right), Some(VerticalSide::Top)) => DockPosition::TopRight,
+            (Some(HorizontalSide::Left), Some(VerticalSide::Bottom)) => DockPosition::BottomLeft,
+            (Some(HorizontalSide::Right), Some(VerticalSide::Bottom)) => DockPosition::BottomRight,
+        }
+    }
+}
+
+/// * 도킹 계산 설정 (물리 픽셀)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapConfig {
+    pub snap_distance: i32,   // 가장자리와 이 거리 안이면 도킹
+    pub undock_distance: i32, // 도킹된 뒤에는 이 거리를 넘게 끌어야 해제 (hysteresis)
+    pub edges: DockEdges,     // 가장자리별 도킹 허용 여부
+}
+
+/// * 도킹 계산 결과
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Snap {
+    pub x: i32,
+    pub y: i32,
+    pub sides: DockSides,
+}
+
+/// + 한 축의 도킹 방향 결정
+// ? `near`, `far`: 시작/끝 가장자리까지 거리 (창이 가장자리를 넘어가면 음수)
+// ? 이미 도킹된 가장자리는 undock_distance, 새 가장자리는 snap_distance 안에서만 도킹
+// ? 가장자리를 snap_distance보다 많이 넘어간 창은 다른 모니터로 옮기는 중일 수 있으므로 도킹하지 않음
+fn snap_axis<S: Copy + PartialEq>(
+    previous: Option<S>,
+    (near_side, near, near_enabled): (S, i32, bool),
+    (far_side, far, far_enabled): (S, i32, bool),
+    config: &SnapConfig,
+) -> Option<S> {
+    let within = |side: S, distance: i32, enabled: bool| {
+        let limit = if previous == Some(side) {
+            config.undock_distance.max(config.snap_distance)
+        } else {
+            config.snap_distance
+        };
+        enabled && distance.abs() <= limit
+    };
+
+    let near_ok = within(near_side, near, near_enabled);
+    let far_ok = within(far_side, far, far_enabled);
+
+    match (near_ok, far_ok) {
+        // ? 작업 영역보다 창이 큰 경우 등 양쪽 모두 가능하면 이전 방향 유지, 없으면 가까운 쪽
+        (true, true) if previous == Some(far_side) => Some(far_side),
+        (true, true) => Some(if near.abs() <= far.abs() {
+            near_side
+        } else {
+            far_side
+        }),
+        (true, false) => Some(near_side),
+        (false, true) => Some(far_side),
+        (false, false) => None,
+    }
+}
+
+/// + 창 위치와 작업 영역으로 도킹 위치 계산
+pub fn snap_window(window: &Rect, area: &Rect, previous: DockSides, config: &SnapConfig) -> Snap {
+    let edges = config.edges;
+
+    let horizontal = snap_axis(
+        previous.horizontal,
+        (HorizontalSide::Left, window.x - area.x, edges.left),
+        (
+            HorizontalSide::Right,
+            area.right() - window.right(),
+            edges.right,
+        ),
+        config,
+    );
+    let vertical = snap_axis(
+        previous.vertical,
+        (VerticalSide::Top, window.y - area.y, edges.top),
+        (
+            VerticalSide::Bottom,
+            area.bottom() - window.bottom(),
+            edges.bottom,
+        ),
+        config,
+    );
+
+    let x = match horizontal {
+        Some(HorizontalSide::Left) => area.x,
+        Some(HorizontalSide::Right) => area.right() - window.width as i32,
+        None => window.x,
+    };
+    let y = match vertical {
+        Some(VerticalSide::Top) => area.y,
+        Some(VerticalSide::Bottom) => area.bottom() - window.height as i32,
+        None => window.y,
+    };
+
+    Snap {
+        x,
+        y,
+        sides: DockSides {
+            horizontal,
+            vertical,
+        },
+    }
+}
+
+/// + 자동 숨기기 위치 계산 (reveal_strip 만큼만 작업 영역 안에 남기고 도킹된 가장자리 밖으로 이동)
+// ? 모서리에 도킹된 경우 가로(왼쪽/오른쪽) 방향으로 숨김
+pub fn hidden_position(
+    window: &Rect,
+    area: &Rect,
+    sides: DockSides,
+    reveal_strip: i32,
+) -> Option<(i32, i32)> {
+    let width = window.width as i32;
+    let height = window.height as i32;
+
+    match (sides.horizontal, sides.vertical) {
+        (Some(HorizontalSide::Left), _) => Some((area.x - width + reveal_strip, window.y)),
+        (Some(HorizontalSide::Right), _) => Some((area.right() - reveal_strip, window.y)),
+        (None, Some(VerticalSide::Top)) => Some((window.x, area.y - height + reveal_strip)),
+        (None, Some(VerticalSide::Bottom)) => Some((window.x, area.bottom() - reveal_strip)),
+        (None, None) => None,
+    }
+}
+
+// > 도킹 위치 계산
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use HorizontalSide::{Left, Right};
+    use VerticalSide::{Bottom, Top};
+
+    const AREA: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1040,
+    };
+    // ? 주 모니터 왼쪽에 있고 위쪽이 200px 내려간 보조 모니터 (right = 0, bottom = 1184)
+    const OFFSET_AREA: Rect = Rect {
+        x: -1280,
+        y: 200,
+        width: 1280,
+        height: 984,
+    };
+    const ALL_EDGES: DockEdges = DockEdges {
+        left: true,
+        right: true,
+        top: true,
+        bottom: true,
+    };
+
+    fn sides(horizontal: Option<HorizontalSide>, vertical: Option<VerticalSide>) -> DockSides {
+        DockSides {
+            horizontal,
+            vertical,
+        }
+    }
+
+    fn window(x: i32, y: i32) -> Rect {
+        Rect::new(x, y, 300, 400)
+    }
+
+    /// * 테스트 케이스 (창, 작업 영역, 이전 도킹 상태, 허용 가장자리 → 기대 위치, 도킹 위치)
+    struct Case {
+        name: &'static str,
+        window: Rect,
+        area: Rect,
+        previous: DockSides,
+        edges: DockEdges,
+        expected: (i32, i32, DockPosition),
+    }
+
+    fn case(name: &'static str, window: Rect, expected: (i32, i32, DockPosition)) -> Case {
+        Case {
+            name,
+            window,
+            area: AREA,
+            previous: DockSides::default(),
+            edges: ALL_EDGES,
+            expected,
+        }
+    }
+
+    fn run(cases: Vec<Case>) {
+        for case in cases {
+            let config = SnapConfig {
+                snap_distance: 20,
+                undock_distance: 60,
+                edges: case.edges,
+            };
+            let snap = snap_window(&case.window, &case.area, case.previous, &config);
+
+            assert_eq!(
+                (snap.x, snap.y, snap.sides.position()),
+                case.expected,
+                "{}",
+                case.name
+            );
+        }
+    }
+
+    #[test]
+    fn snaps_to_each_edge_and_corner() {
+        run(vec![
+            case("left", window(15, 300), (0, 300, DockPosition::Left)),
+            case(
+                "left overlap",
+                window(-15, 300),
+                (0, 300, DockPosition::Left),
+            ),
+            case("right", window(1610, 300), (1620, 300, DockPosition::Right)),
+            case("top", window(800, 12), (800, 0, DockPosition::Top)),
+            case("bottom", window(800, 622), (800, 640, DockPosition::Bottom)),
+            case("top left", window(5, 5), (0, 0, DockPosition::TopLeft)),
+            case(
+                "top right",
+                window(1615, -8),
+                (1620, 0, DockPosition::TopRight),
+            ),
+            case(
+                "bottom left",
+                window(-3, 630),
+                (0, 640, DockPosition::BottomLeft),
+            ),
+            case(
+                "bottom right",
+                window(1625, 645),
+                (1620, 640, DockPosition::BottomRight),
+            ),
+            case(
+                "snap boundary",
+                window(20, 300),
+                (0, 300, DockPosition::Left),
+            ),
+            case(
+                "outside snap",
+                window(21, 300),
+                (21, 300, DockPosition::Floating),
+            ),
+            case(
+                "center",
+                window(800, 300),
+                (800, 300, DockPosition::Floating),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn stays_docked_until_undock_distance() {
+        run(vec![
+            Case {
+                previous: sides(Some(Left), None),
+                ..case("left within", window(50, 300), (0, 300, DockPosition::Left))
+            },
+            Case {
+                previous: sides(Some(Left), None),
+                ..case(
+                    "left boundary",
+                    window(60, 300),
+                    (0, 300, DockPosition::Left),
+                )
+            },
+            Case {
+                previous: sides(Some(Left), None),
+                ..case(
+                    "left undock",
+                    window(61, 300),
+                    (61, 300, DockPosition::Floating),
+                )
+            },
+            Case {
+                previous: sides(Some(Right), None),
+                ..case(
+                    "right within",
+                    window(1565, 300),
+                    (1620, 300, DockPosition::Right),
+                )
+            },
+            Case {
+                previous: sides(None, Some(Top)),
+                ..case("top within", window(800, 40), (800, 0, DockPosition::Top))
+            },
+            Case {
+                previous: sides(None, Some(Bottom)),
+                ..case(
+                    "bottom undock",
+                    window(800, 570),
+                    (800, 570, DockPosition::Floating),
+                )
+            },
+            Case {
+                previous: sides(Some(Left), Some(Top)),
+                ..case(
+                    "corner keeps one side",
+                    window(5, 100),
+                    (0, 100, DockPosition::Left),
+                )
+            },
+            // ? 이전에 도킹되지 않은 가장자리는 snap_distance 기준
+            case(
+                "top not docked",
+                window(800, 40),
+                (800, 40, DockPosition::Floating),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn ignores_disabled_edges() {
+        let no_left = DockEdges {
+            left: false,
+            ..ALL_EDGES
+        };
+        let no_top = DockEdges {
+            top: false,
+            ..ALL_EDGES
+        };
+        let none = DockEdges {
+            left: false,
+            right: false,
+            top: false,
+            bottom: false,
+        };
+
+        run(vec![
+            Case {
+                edges: no_left,
+                ..case(
+                    "left disabled",
+                    window(10, 300),
+                    (10, 300, DockPosition::Floating),
+                )
+            },
+            Case {
+                edges: no_left,
+                ..case(
+                    "right still enabled",
+                    window(1610, 300),
+                    (1620, 300, DockPosition::Right),
+                )
+            },
+            Case {
+                edges: no_top,
+                ..case(
+                    "corner without top",
+                    window(5, 5),
+                    (0, 5, DockPosition::Left),
+                )
+            },
+            Case {
+                edges: none,
+                ..case("all disabled", window(5, 5), (5, 5, DockPosition::Floating))
+            },
+            Case {
+                edges: no_left,
+                previous: sides(Some(Left), None),
+                ..case(
+                    "docked edge disabled",
+                    window(0, 300),
+                    (0, 300, DockPosition::Floating),
+                )
+            },
+        ]);
+    }
+
+    #[test]
+    fn handles_windows_outside_the_work_area() {
+        run(vec![
+            case(
+                "partly off left",
+                window(-100, 300),
+                (-100, 300, DockPosition::Floating),
+            ),
+            case(
+                "partly off bottom",
+                window(800, 900),
+                (800, 900, DockPosition::Floating),
+            ),
+            case(
+                "fully off left",
+                window(-500, 300),
+                (-500, 300, DockPosition::Floating),
+            ),
+            case(
+                "fully off right",
+                window(2500, 300),
+                (2500, 300, DockPosition::Floating),
+            ),
+            Case {
+                previous: sides(Some(Left), None),
+                ..case(
+                    "docked pulled back",
+                    window(-50, 300),
+                    (0, 300, DockPosition::Left),
+                )
+            },
+            Case {
+                previous: sides(Some(Right), Some(Bottom)),
+                ..case(
+                    "docked fully off",
+                    window(2500, 1500),
+                    (2500, 1500, DockPosition::Floating),
+                )
+            },
+        ]);
+    }
+
+    #[test]
+    fn snaps_inside_offset_work_area() {
+        let offset = |name, window, expected| Case {
+            area: OFFSET_AREA,
+            ..case(name, window, expected)
+        };
+
+        run(vec![
+            offset("left", window(-1270, 500), (-1280, 500, DockPosition::Left)),
+            offset(
+                "top right",
+                window(-290, 190),
+                (-300, 200, DockPosition::TopRight),
+            ),
+            offset(
+                "bottom",
+                window(-800, 779),
+                (-800, 784, DockPosition::Bottom),
+            ),
+            offset(
+                "bottom left",
+                window(-1290, 790),
+                (-1280, 784, DockPosition::BottomLeft),
+            ),
+            offset(
+                "center",
+                window(-800, 500),
+                (-800, 500, DockPosition::Floating),
+            ),
+            // ? 원점 기준이었다면 왼쪽/위에 도킹될 위치
+            offset("origin", window(5, 5), (5, 5, DockPosition::Floating)),
+        ]);
+    }
+
+    #[test]
+    fn tall_window_keeps_previous_side() {
+        let tall = Rect::new(800, 0, 300, 1040);
+
+        run(vec![
+            case("no previous", tall, (800, 0, DockPosition::Top)),
+            Case {
+                previous: sides(None, Some(Bottom)),
+                ..case("previous bottom", tall, (800, 0, DockPosition::Bottom))
+            },
+        ]);
+    }
+
+    #[test]
+    fn restores_sides_from_saved_position() {
+        let positions = [
+            DockPosition::Floating,
+            DockPosition::Left,
+            DockPosition::Right,
+            DockPosition::Top,
+            DockPosition::Bottom,
+            DockPosition::TopLeft,
+            DockPosition::TopRight,
+            DockPosition::BottomLeft,
+            DockPosition::BottomRight,
+        ];
+        for position in positions {
+            assert_eq!(DockSides::from_position(position).position(), position);
+        }
+
+        // ? 복원된 가장자리는 snap_distance가 아닌 undock_distance 기준으로 유지
+        run(vec![Case {
+            previous: DockSides::from_position(DockPosition::Right),
+            ..case(
+                "restored right",
+                window(1580, 300),
+                (1620, 300, DockPosition::Right),
+            )
+        }]);
+    }
+}
@@ -1,2 +1,6 @@
+pub mod anchor;
+pub mod auto_hide;
 pub mod dock_events;
 pub mod dock_manager;
+pub mod geometry;
+pub mod monitor;
@@ -0,0 +1,73 @@
+use crate::window_utils::window_system::{window_system, Rect};
+use tauri::{Monitor, WebviewWindow};
+
+// > 모니터 영역
+// ? 모든 좌표는 물리 좌표 (주 모니터 왼쪽/위에 있는 보조 모니터는 음수 좌표)
+
+/// * 모니터 영역 구조체
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorArea {
+    pub id: String,        // 모니터 이름 (없으면 위치로 대체)
+    pub bounds: Rect,      // 모니터 전체 영역
+    pub work_area: Rect,   // 작업 표시줄을 제외한 영역
+    pub scale_factor: f64, // 모니터 배율 (1.0 = 100%)
+}
+
+impl MonitorArea {
+    fn from_monitor(monitor: &Monitor) -> Self {
+        let position = monitor.position();
+        let size = monitor.size();
+        let bounds = Rect::new(position.x, position.y, size.width, size.height);
+
+        Self {
+            id: monitor
+                .name()
+                .cloned()
+                .unwrap_or_else(|| format!("{},{}", bounds.x, bounds.y)),
+            bounds,
+            work_area: window_system().work_area(&bounds).unwrap_or(bounds),
+            scale_factor: monitor.scale_factor(),
+        }
+    }
+
+    /// + 논리 픽셀 거리를 이 모니터의 물리 픽셀 거리로 변환
+    pub fn scale(&self, logical: u32) -> i32 {
+        (logical as f64 * self.scale_factor).round() as i32
+    }
+}
+
+/// + 연결된 모든 모니터 영역 (모니터 정보를 가져올 수 없으면 빈 목록)
+pub fn monitor_areas(window: &WebviewWindow) -> Vec<MonitorArea> {
+    match window.available_monitors() {
+        Ok(monitors) => monitors.iter().map(MonitorArea::from_monitor).collect(),
+        Err(e) => {
+            eprintln!("❌ 모니터 정보를 가져올 수 없습니다: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// + 창 중심이 있는 모니터 (없으면 가장 많이 겹치는 모니터, 겹치지도 않으면 중심이 가장 가까운 모니터)
+pub fn monitor_for_rect<'a>(monitors: &'a [MonitorArea], rect: &Rect) -> Option<&'a MonitorArea> {
+    let (x, y) = rect.center();
+    if let Some(monitor) = monitors
+        .iter()
+        .find(|monitor| monitor.bounds.contains(x, y))
+    {
+        return Some(monitor);
+    }
+
+    let overlapping = monitors
+        .iter()
+        .filter(|monitor| monitor.bounds.overlap_area(rect) > 0)
+        .max_by_key(|monitor| monitor.bounds.overlap_area(rect));
+
+    overlapping.or_else(|| {
+        monitors.iter().min_by_key(|monitor| {
+            let (cx, cy) = monitor.bounds.center();
+            (cx - x).unsigned_abs() as u64 + (cy - y).unsigned_abs() as u64
+        })
+    })
+}
+
+// > 모니터 영역
@@ -10,19 +10,30 @@ mod update; // * Program Update Mod
 mod window_utils; // * 각종 Window Util Mod
 
 use audio::audio_manager;
+use dock::anchor::start_anchor_follower;
+use dock::auto_hide::start_auto_hide;
 use dock::dock_events::setup_window_events;
 use dock::dock_manager::DockManager;
 use dotenv::dotenv;
-use state::store::{get_app_data_dir, init_state, load_state};
+use state::catalogue::sync_bundled_catalogue;
+use state::persist::{flush_blocking, start_persist_worker};
+use state::reset::start_reset_scheduler;
+use state::store::{get_app_data_dir, init_state};
 use std::{env, sync::Arc};
-use tauri::{Emitter, Listener, Manager, State, Window};
+use tauri::{Emitter, Listener, Manager, RunEvent, State, Window};
 use tauri_plugin_dialog::DialogExt;
 use tokio::sync::Mutex;
 use ui::animations::{
     animate_window_resize, ease_in_out_back, ease_in_out_expo, ease_in_out_quad, ease_in_out_quart,
 };
+use update::rollback::{check_pending_update, confirm_update_health};
+use update::scheduler::start_update_scheduler;
 use update::{get_update_check_result, run_update_with_info};
-use window_utils::{auto_focus_shift::start_mouse_tracking, window_os_info::get_os_info};
+use window_utils::{
+    auto_focus_shift::{start_mouse_tracking, watch_window_visibility},
+    game_detect::start_game_detection,
+    window_os_info::get_os_info,
+};
 
 pub const WINDOW_LABEL: &str = "main";
 
@@ -116,8 +127,12 @@ pub fn run() {
     // * AppData dir path
     println!("{:?}", get_app_data_dir());
 
+    // * 업데이트 후 첫 실행 확인 (이전 실행이 실패했다면 롤백 후 종료)
+    check_pending_update(env!("CARGO_PKG_VERSION"));
     // * 전역 상태 초기화 (앱 실행 전에 한 번만 실행)
     init_state();
+    // * 기본 레이드 카탈로그 갱신
+    sync_bundled_catalogue();
     // > 전역 상태 관리
 
     tauri::Builder::default()
@@ -132,12 +147,20 @@ pub fn run() {
 
             window_for_once.once("frontend-ready", move |_| {
                 println!("✅ 프로그램 실행시 1회성 함수 실행 : window.once");
+                confirm_update_health(); // ? 업데이트 후 정상 실행 확인
                 let _ = window_for_emit.emit("on:app_start_once", ());
             });
 
+            start_auto_hide(window.clone(), Arc::clone(&dock_manager));
+            start_anchor_follower(window.clone());
             setup_window_events(&window, dock_manager);
             // window.set_ignore_cursor_events(true).unwrap(); // 기본적으로 클릭 스루 활성화
+            watch_window_visibility(&window);
             start_mouse_tracking(window);
+            start_persist_worker(app.handle().clone());
+            start_reset_scheduler(app.handle().clone());
+            start_update_scheduler(app.handle().clone());
+            start_game_detection(app.handle().clone());
 
             Ok(())
         })
@@ -147,6 +170,7 @@ pub fn run() {
                 let _ = window.unminimize();
                 let _ = window.show();
                 let _ = window.set_focus();
+                window_utils::auto_focus_shift::resume_mouse_tracking();
 
                 window
                     .dialog()
@@ -161,7 +185,6 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_sql::Builder::default().build())
         .manage(state)
-        .manage(Arc::new(Mutex::new(load_state())))
         // ? Frontend 에서 Rust 함수 호출을 위해서 invoke handler에 등록
         .invoke_handler(tauri::generate_handler![
             exit_app,                                          // * Process Exit Fn
@@ -172,20 +195,56 @@ pub fn run() {
             state::store::set_gold,                            // * Set Gold Fn
             state::store::increment_gold,                      // * Increment Gold Fn
             state::store::decrement_gold,                      // * Decrement Gold Fn
+            state::ledger::get_gold_history,                   // * Get Gold Ledger History
+            state::ledger::get_weekly_gold_total,              // * Get Weekly Gold Total
+            state::ledger::get_gold_by_character,              // * Get Weekly Gold By Character
+            state::ledger::undo_last_gold_entry,               // * Undo Last Gold Entry
+            state::income::get_weekly_income,                  // * Get Weekly Gold Income
             state::store::get_user_settings,                   // * Get User Settings Fn
             state::store::set_user_settings,                   // * Set User Settings Fn
             state::store::get_position,                        // * Get Window Position
             state::store::set_position,                        // * Set Window Position
+            state::store::get_dock_state,                      // * Get Window Dock State
             state::store::get_default_tab,                     // * Get Default Tab
-            state::store::set_game_title,                      // * Set Game Title
+            state::homework::get_homework,                     // * Get Homework
+            state::catalogue::get_last_catalogue_sync,         // * Get Raid Catalogue Sync Result
+            state::homework::upsert_character,                 // * Upsert Character
+            state::homework::remove_character,                 // * Remove Character
+            state::homework::set_characters,                   // * Set Character List
+            state::homework::upsert_group_raid,                // * Upsert Group Raid
+            state::homework::remove_group_raid,                // * Remove Group Raid
+            state::homework::set_group_raids,                  // * Set Group Raid List
+            state::homework::set_live_raids,                   // * Set Character Live Raids
+            state::homework::replace_live_raids,               // * Replace All Live Raids
+            state::homework::set_live_raid_complete,           // * Set Live Raid Complete
+            state::homework::import_legacy_homework,           // * Import SQLite Homework
+            state::homework::is_legacy_homework_imported,      // * Check SQLite Homework Imported
+            state::reset::get_reset_info,                      // * Get Weekly Reset Info
+            state::reset::reset_homework,                      // * Manual Homework Reset
+            state::reset::set_biweekly_reset_week,             // * Set Bi-weekly Reset Week
             window_utils::window_search::find_window_by_title, // * Find Process Title
             window_utils::window_search::get_window_titles,    // // Find All Process Title
+            window_utils::window_search::preview_title_matches, // * Preview Matching Windows
+            window_utils::game_detect::get_detected_game,      // * Get Detected Game Client
             window_utils::auto_focus_shift::pause_auto_focus,  // * Auto Focus Pause
             window_utils::auto_focus_shift::resume_auto_focus, // * Auto focus Resume
+            window_utils::auto_focus_shift::suspend_mouse_tracking, // * Suspend Tracking While Hidden
+            window_utils::auto_focus_shift::resume_mouse_tracking, // * Resume Tracking
             get_os_info,                                       // * OS information
             get_update_check_result,                           // * Update Check
             run_update_with_info,                              // * Run Update
+            update::raids::check_raid_catalogue_update,        // * Remote Raid Catalogue Update
+            update::changelog::get_whats_new,                  // * Get Release Notes Since Last Seen
+            update::changelog::mark_whats_new_seen,            // * Mark Release Notes Seen
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let RunEvent::Exit = event {
+                // ? 종료 전 저장되지 않은 상태 저장
+                if let Err(e) = flush_blocking() {
+                    eprintln!("❌ 종료 시 상태 저장 실패: {}", e);
+                }
+            }
+        });
 }
@@ -0,0 +1,316 @@
+use super::homework::{Homework, Raid};
+use super::persist::mark_dirty;
+use super::store::get_state;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, sync::RwLock};
+
+// > 기본 레이드 카탈로그
+// ? 프로그램에 포함된 default_raids.json을 기준으로 숙제표의 기본 레이드를 갱신
+// ? raidId는 유지하고, 카탈로그에서 빠진 레이드는 삭제하지 않고 retired로 표시
+// ! version은 원격 카탈로그(raids.json)와 같은 번호를 공유하므로 새 카탈로그는 어느 쪽이든 다음 번호 사용
+
+static BUNDLED_CATALOGUE_JSON: &str = include_str!("../../data/default_raids.json");
+
+static BUNDLED_CATALOGUE: Lazy<RaidCatalogue> = Lazy::new(|| {
+    serde_json::from_str(BUNDLED_CATALOGUE_JSON).expect("default_raids.json 파싱 실패")
+});
+
+/// ? 마지막 카탈로그 동기화 결과 (Frontend 조회용)
+static LAST_SYNC: Lazy<RwLock<Option<CatalogueDiff>>> = Lazy::new(|| RwLock::new(None));
+
+/// * 카탈로그 레이드 구조체 (priority는 목록 순서로 결정)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogueRaid {
+    pub raid_id: u32,
+    pub level: u32,
+    pub raid_name: String,
+    pub difficulty: String,
+    pub gate: String,
+    pub reward: u32,
+    pub reset_type: u8,
+}
+
+/// * 레이드 카탈로그 구조체
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RaidCatalogue {
+    pub version: u32,
+    pub raids: Vec<CatalogueRaid>,
+}
+
+/// * 카탈로그 동기화 결과 구조체
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogueDiff {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub added: Vec<u32>,   // 새로 추가된 raidId
+    pub updated: Vec<u32>, // 내용이 변경된 raidId (retired 해제 포함)
+    pub retired: Vec<u32>, // 카탈로그에서 빠진 raidId
+}
+
+impl CatalogueRaid {
+    /// + 숙제표 레이드로 변환
+    fn to_raid(&self, priority: u32) -> Raid {
+        Raid {
+            raid_id: self.raid_id,
+            priority,
+            level: self.level,
+            raid_name: self.raid_name.clone(),
+            difficulty: self.difficulty.clone(),
+            gate: self.gate.clone(),
+            reward: self.reward,
+            reset_type: self.reset_type,
+            retired: false,
+        }
+    }
+}
+
+impl RaidCatalogue {
+    /// + 카탈로그 내용 검증 (외부에서 받은 카탈로그 적용 전)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.raids.is_empty() {
+            return Err("레이드 목록이 비어있습니다.".into());
+        }
+
+        let mut ids = HashSet::new();
+        for raid in &self.raids {
+            if raid.raid_id == 0 || !ids.insert(raid.raid_id) {
+                return Err(format!("잘못되었거나 중복된 raidId: {}", raid.raid_id));
+            }
+            if raid.raid_name.trim().is_empty()
+                || raid.difficulty.trim().is_empty()
+                || raid.gate.trim().is_empty()
+            {
+                return Err(format!("비어있는 레이드 정보: {}", raid.raid_id));
+            }
+            if !matches!(raid.reset_type, 1 | 2) {
+                return Err(format!(
+                    "잘못된 resetType: {} ({})",
+                    raid.reset_type, raid.raid_id
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// + 프로그램에 포함된 기본 레이드 카탈로그
+pub fn bundled_catalogue() -> &'static RaidCatalogue {
+    &BUNDLED_CATALOGUE
+}
+
+impl Homework {
+    /// + 카탈로그 버전이 더 높으면 기본 레이드를 갱신하고 변경 내역 반환
+    pub fn sync_default_raids(&mut self, catalogue: &RaidCatalogue) -> Option<CatalogueDiff> {
+        if catalogue.version <= self.default_raids_version && !self.default_raids.is_empty() {
+            return None;
+        }
+
+        let mut diff = CatalogueDiff {
+            from_version: self.default_raids_version,
+            to_version: catalogue.version,
+            ..Default::default()
+        };
+
+        for (index, entry) in catalogue.raids.iter().enumerate() {
+            let raid = entry.to_raid(index as u32 + 1);
+
+            match self
+                .default_raids
+                .iter_mut()
+                .find(|r| r.raid_id == raid.raid_id)
+            {
+                Some(existing) => {
+                    // ? 순서(priority)만 바뀐 경우는 변경 내역에 포함하지 않음
+                    existing.priority = raid.priority;
+                    if *existing != raid {
+                        *existing = raid;
+                        diff.updated.push(entry.raid_id);
+                    }
+                }
+                None => {
+                    self.default_raids.push(raid);
+                    diff.added.push(entry.raid_id);
+                }
+            }
+        }
+
+        // ? 카탈로그에서 빠진 레이드는 live_raids가 깨지지 않도록 retired로만 표시
+        for raid in &mut self.default_raids {
+            if !raid.retired && !catalogue.raids.iter().any(|r| r.raid_id == raid.raid_id) {
+                raid.retired = true;
+                diff.retired.push(raid.raid_id);
+            }
+        }
+
+        self.default_raids.sort_by_key(|r| (r.retired, r.priority));
+        self.default_raids_version = catalogue.version;
+        self.refresh_group_rewards(); // ? 그룹 레이드 골드는 기본 레이드 골드 합계
+        Some(diff)
+    }
+}
+
+/// + 카탈로그 동기화 결과 기록 (저장 예약, Frontend 조회용 보관)
+pub fn record_catalogue_sync(diff: &CatalogueDiff) {
+    println!(
+        "📦 기본 레이드 카탈로그 갱신 v{} → v{} (추가 {}, 변경 {}, 제외 {})",
+        diff.from_version,
+        diff.to_version,
+        diff.added.len(),
+        diff.updated.len(),
+        diff.retired.len()
+    );
+    mark_dirty();
+    *LAST_SYNC.write().unwrap() = Some(diff.clone());
+}
+
+/// + 프로그램에 포함된 카탈로그로 기본 레이드 동기화 (실행 시 1회)
+pub fn sync_bundled_catalogue() {
+    let mut app_state = get_state().blocking_lock();
+    let Some(diff) = app_state.homework.sync_default_raids(bundled_catalogue()) else {
+        return;
+    };
+    drop(app_state); // ? 상태 락 해제
+
+    record_catalogue_sync(&diff);
+}
+
+// > 기본 레이드 카탈로그
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+/// ? 이번 실행에서 적용된 카탈로그 변경 내역 (없으면 None)
+#[tauri::command]
+pub fn get_last_catalogue_sync() -> Option<CatalogueDiff> {
+    LAST_SYNC.read().unwrap().clone()
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(raid_id: u32, reward: u32) -> CatalogueRaid {
+        CatalogueRaid {
+            raid_id,
+            level: 1600,
+            raid_name: format!("레이드{}", raid_id),
+            difficulty: "노말".into(),
+            gate: "1".into(),
+            reward,
+            reset_type: 1,
+        }
+    }
+
+    fn catalogue(version: u32, raids: Vec<CatalogueRaid>) -> RaidCatalogue {
+        RaidCatalogue { version, raids }
+    }
+
+    fn synced(raids: Vec<CatalogueRaid>) -> Homework {
+        let mut homework = Homework::default();
+        homework.sync_default_raids(&catalogue(1, raids)).unwrap();
+        homework
+    }
+
+    #[test]
+    fn first_sync_adds_all_raids() {
+        let mut homework = Homework::default();
+        let diff = homework
+            .sync_default_raids(&catalogue(1, vec![entry(1, 100), entry(2, 200)]))
+            .unwrap();
+
+        assert_eq!(diff.added, vec![1, 2]);
+        assert_eq!(homework.default_raids_version, 1);
+        assert_eq!(homework.default_raids[1].priority, 2);
+    }
+
+    #[test]
+    fn older_or_same_version_is_ignored() {
+        let mut homework = synced(vec![entry(1, 100)]);
+
+        assert!(homework
+            .sync_default_raids(&catalogue(1, vec![entry(1, 999)]))
+            .is_none());
+        assert!(homework
+            .sync_default_raids(&catalogue(0, vec![entry(1, 999)]))
+            .is_none());
+        assert_eq!(homework.default_raids[0].reward, 100);
+    }
+
+    #[test]
+    fn changed_raid_is_updated_in_place() {
+        let mut homework = synced(vec![entry(1, 100), entry(2, 200)]);
+
+        let diff = homework
+            .sync_default_raids(&catalogue(
+                2,
+                vec![entry(1, 150), entry(2, 200), entry(3, 300)],
+            ))
+            .unwrap();
+
+        assert_eq!(diff.updated, vec![1]);
+        assert_eq!(diff.added, vec![3]);
+        assert_eq!(homework.default_raids[0].reward, 150);
+    }
+
+    #[test]
+    fn reorder_only_is_not_reported_as_update() {
+        let mut homework = synced(vec![entry(1, 100), entry(2, 200)]);
+
+        let diff = homework
+            .sync_default_raids(&catalogue(2, vec![entry(2, 200), entry(1, 100)]))
+            .unwrap();
+
+        assert!(diff.updated.is_empty());
+        // ? 순서는 카탈로그를 따름
+        assert_eq!(homework.default_raids[0].raid_id, 2);
+        assert_eq!(homework.default_raids[0].priority, 1);
+    }
+
+    #[test]
+    fn removed_raid_is_retired_and_can_return() {
+        let mut homework = synced(vec![entry(1, 100), entry(2, 200)]);
+
+        let diff = homework
+            .sync_default_raids(&catalogue(2, vec![entry(2, 200)]))
+            .unwrap();
+        assert_eq!(diff.retired, vec![1]);
+        assert_eq!(homework.default_raids.len(), 2); // ? 삭제하지 않음
+        assert!(homework.default_raids.last().unwrap().retired);
+
+        // ? 다시 카탈로그에 포함되면 retired 해제 (변경으로 기록)
+        let diff = homework
+            .sync_default_raids(&catalogue(3, vec![entry(1, 100), entry(2, 200)]))
+            .unwrap();
+        assert_eq!(diff.updated, vec![1]);
+        assert!(diff.retired.is_empty());
+        assert!(homework.default_raids.iter().all(|r| !r.retired));
+    }
+
+    #[test]
+    fn validate_rejects_bad_catalogues() {
+        assert!(catalogue(1, vec![]).validate().is_err());
+        assert!(catalogue(1, vec![entry(0, 100)]).validate().is_err());
+        assert!(catalogue(1, vec![entry(1, 100), entry(1, 200)])
+            .validate()
+            .is_err());
+
+        let mut blank = entry(1, 100);
+        blank.gate = " ".into();
+        assert!(catalogue(1, vec![blank]).validate().is_err());
+
+        let mut reset = entry(1, 100);
+        reset.reset_type = 3;
+        assert!(catalogue(1, vec![reset]).validate().is_err());
+
+        assert!(catalogue(1, vec![entry(1, 100)]).validate().is_ok());
+    }
+
+    #[test]
+    fn bundled_catalogue_is_valid() {
+        assert!(bundled_catalogue().validate().is_ok());
+    }
+}
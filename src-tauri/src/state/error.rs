@@ -0,0 +1,40 @@
+use std::{fmt, io};
+
+/// * 상태 파일 저장/불러오기 에러
+#[derive(Debug)]
+pub enum StoreError {
+    NoAppDataDir,                 // AppData 폴더를 찾을 수 없음
+    Io(io::Error),                // 파일 입출력 실패
+    Serialize(serde_json::Error), // JSON 직렬화 실패
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::NoAppDataDir => write!(f, "AppData 폴더를 찾을 수 없습니다."),
+            StoreError::Io(e) => write!(f, "설정 파일 저장 실패: {}", e),
+            StoreError::Serialize(e) => write!(f, "설정 데이터 변환 실패: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<io::Error> for StoreError {
+    fn from(e: io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for StoreError {
+    fn from(e: serde_json::Error) -> Self {
+        StoreError::Serialize(e)
+    }
+}
+
+/// ? Tauri Command에서 `?`로 바로 Frontend 메세지로 변환
+impl From<StoreError> for String {
+    fn from(e: StoreError) -> Self {
+        e.to_string()
+    }
+}
@@ -0,0 +1,647 @@
+use super::persist::mark_dirty;
+use super::store::get_state;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+// > 숙제표 도메인 모델
+// ? Frontend(types.ts)와 동일한 camelCase 필드명을 사용
+
+/// * 레이드 출처 (기본 레이드 / 그룹 레이드)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RaidSource {
+    Default,
+    Group,
+}
+
+/// * 캐릭터 구조체 (characters 테이블)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Character {
+    pub char_id: u32,
+    pub priority: u32,
+    pub char_name: String,
+    pub class: String,
+    pub color: String,
+}
+
+/// * 레이드 구조체 (default_raids, group_raids 테이블)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Raid {
+    pub raid_id: u32,
+    pub priority: u32,
+    pub level: u32,
+    pub raid_name: String,
+    pub difficulty: String,
+    pub gate: String,
+    pub reward: u32,
+    pub reset_type: u8, // 1: 매주, 2: 2주
+    #[serde(default)]
+    pub retired: bool, // 카탈로그에서 빠진 기본 레이드 여부
+}
+
+/// * 그룹 레이드 구조체 (group_raids + group_raids_map 테이블)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RaidGroup {
+    #[serde(flatten)]
+    pub raid: Raid,
+    pub raid_ids: Vec<u32>, // 그룹에 포함된 기본 레이드 raidId 목록
+}
+
+/// * 숙제 진행 구조체 (live_raids 테이블)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveRaid {
+    pub id: u32,
+    pub char_id: u32,
+    pub raid_type: RaidSource,
+    pub raid_id: u32,
+    pub complete: bool,
+    pub swap: u32,
+    pub reset_type: u8,
+}
+
+/// * 숙제표 전체 상태 구조체
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct Homework {
+    pub characters: Vec<Character>,
+    pub default_raids: Vec<Raid>,
+    pub default_raids_version: u32, // 적용된 기본 레이드 카탈로그 버전
+    pub group_raids: Vec<RaidGroup>,
+    pub live_raids: Vec<LiveRaid>,
+    pub legacy_imported: bool, // 이전 버전 SQLite(live.db) 숙제표를 가져왔는지 여부
+}
+
+impl Homework {
+    /// + 캐릭터 추가 또는 덮어쓰기
+    pub fn upsert_character(&mut self, character: Character) {
+        match self
+            .characters
+            .iter_mut()
+            .find(|c| c.char_id == character.char_id)
+        {
+            Some(existing) => *existing = character,
+            None => self.characters.push(character),
+        }
+        self.characters.sort_by_key(|c| c.priority);
+    }
+
+    /// + 캐릭터 삭제 (해당 캐릭터의 숙제도 함께 삭제)
+    pub fn remove_character(&mut self, char_id: u32) -> bool {
+        let before = self.characters.len();
+        self.characters.retain(|c| c.char_id != char_id);
+        self.live_raids.retain(|r| r.char_id != char_id);
+        before != self.characters.len()
+    }
+
+    /// + 캐릭터 목록 전체 교체 (목록에서 빠진 캐릭터의 숙제도 함께 삭제)
+    pub fn set_characters(&mut self, characters: Vec<Character>) -> Result<(), String> {
+        let mut ids = HashSet::new();
+        if let Some(duplicate) = characters.iter().find(|c| !ids.insert(c.char_id)) {
+            return Err(format!("중복된 캐릭터입니다: {}", duplicate.char_id));
+        }
+
+        self.characters = characters;
+        self.characters.sort_by_key(|c| c.priority);
+        self.live_raids.retain(|r| ids.contains(&r.char_id));
+        Ok(())
+    }
+
+    /// + 그룹에 포함된 기본 레이드 골드 합계
+    pub fn group_reward(&self, raid_ids: &[u32]) -> u32 {
+        self.default_raids
+            .iter()
+            .filter(|r| raid_ids.contains(&r.raid_id))
+            .map(|r| r.reward)
+            .sum()
+    }
+
+    /// + 그룹 레이드 검증 후 골드를 포함된 기본 레이드 합계로 맞춤
+    fn prepare_group_raid(&self, mut group: RaidGroup) -> Result<RaidGroup, String> {
+        // ? 존재하지 않는 기본 레이드를 포함하면 거부
+        if let Some(missing) = group
+            .raid_ids
+            .iter()
+            .find(|id| !self.default_raids.iter().any(|r| r.raid_id == **id))
+        {
+            return Err(format!("존재하지 않는 레이드입니다: {}", missing));
+        }
+
+        group.raid.reward = self.group_reward(&group.raid_ids);
+        Ok(group)
+    }
+
+    /// + 기본 레이드 골드가 바뀌었을 때 그룹 레이드 골드 다시 계산
+    pub fn refresh_group_rewards(&mut self) {
+        let rewards: Vec<u32> = self
+            .group_raids
+            .iter()
+            .map(|g| self.group_reward(&g.raid_ids))
+            .collect();
+        for (group, reward) in self.group_raids.iter_mut().zip(rewards) {
+            group.raid.reward = reward;
+        }
+    }
+
+    /// + 그룹 레이드 추가 또는 덮어쓰기
+    pub fn upsert_group_raid(&mut self, group: RaidGroup) -> Result<(), String> {
+        let group = self.prepare_group_raid(group)?;
+
+        match self
+            .group_raids
+            .iter_mut()
+            .find(|g| g.raid.raid_id == group.raid.raid_id)
+        {
+            Some(existing) => *existing = group,
+            None => self.group_raids.push(group),
+        }
+        self.group_raids.sort_by_key(|g| g.raid.priority);
+        Ok(())
+    }
+
+    /// + 그룹 레이드 삭제 (해당 그룹을 참조하는 숙제도 함께 삭제)
+    pub fn remove_group_raid(&mut self, raid_id: u32) -> bool {
+        let before = self.group_raids.len();
+        self.group_raids.retain(|g| g.raid.raid_id != raid_id);
+        self.live_raids
+            .retain(|r| !(r.raid_type == RaidSource::Group && r.raid_id == raid_id));
+        before != self.group_raids.len()
+    }
+
+    /// + 그룹 레이드 목록 전체 교체 (목록에서 빠진 그룹을 참조하는 숙제도 함께 삭제)
+    pub fn set_group_raids(&mut self, groups: Vec<RaidGroup>) -> Result<(), String> {
+        let mut ids = HashSet::new();
+        if let Some(duplicate) = groups.iter().find(|g| !ids.insert(g.raid.raid_id)) {
+            return Err(format!(
+                "중복된 그룹 레이드입니다: {}",
+                duplicate.raid.raid_id
+            ));
+        }
+
+        self.group_raids = groups
+            .into_iter()
+            .map(|group| self.prepare_group_raid(group))
+            .collect::<Result<_, _>>()?;
+        self.group_raids.sort_by_key(|g| g.raid.priority);
+        self.live_raids
+            .retain(|r| r.raid_type != RaidSource::Group || ids.contains(&r.raid_id));
+        Ok(())
+    }
+
+    /// + raidType, raidId로 레이드 찾기
+    pub fn find_raid(&self, source: RaidSource, raid_id: u32) -> Option<&Raid> {
+        match source {
+            RaidSource::Default => self.default_raids.iter().find(|r| r.raid_id == raid_id),
+            RaidSource::Group => self
+                .group_raids
+                .iter()
+                .map(|g| &g.raid)
+                .find(|r| r.raid_id == raid_id),
+        }
+    }
+
+    /// + 숙제가 존재하는 캐릭터, 레이드를 가리키는지 확인
+    fn validate_live_raid(&self, raid: &LiveRaid) -> Result<(), String> {
+        if !self.characters.iter().any(|c| c.char_id == raid.char_id) {
+            return Err(format!("존재하지 않는 캐릭터입니다: {}", raid.char_id));
+        }
+        if self.find_raid(raid.raid_type, raid.raid_id).is_none() {
+            return Err(format!("존재하지 않는 레이드입니다: {}", raid.raid_id));
+        }
+        Ok(())
+    }
+
+    /// + 캐릭터의 숙제 목록 전체 교체
+    pub fn set_live_raids(&mut self, char_id: u32, raids: Vec<LiveRaid>) -> Result<(), String> {
+        if !self.characters.iter().any(|c| c.char_id == char_id) {
+            return Err(format!("존재하지 않는 캐릭터입니다: {}", char_id));
+        }
+
+        for raid in &raids {
+            if raid.char_id != char_id {
+                return Err(format!(
+                    "다른 캐릭터의 숙제가 포함되어 있습니다: {}",
+                    raid.id
+                ));
+            }
+            self.validate_live_raid(raid)?;
+        }
+
+        self.live_raids.retain(|r| r.char_id != char_id);
+
+        // ? 새 항목은 기존 id와 겹치지 않도록 재발급
+        let next_id = self.live_raids.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+        for (id, mut raid) in (next_id..).zip(raids) {
+            raid.id = id;
+            self.live_raids.push(raid);
+        }
+        Ok(())
+    }
+
+    /// + 전체 숙제 목록 교체 (숙제표 설정 화면 순서대로 id 재발급)
+    pub fn replace_live_raids(&mut self, raids: Vec<LiveRaid>) -> Result<(), String> {
+        for raid in &raids {
+            self.validate_live_raid(raid)?;
+        }
+
+        self.live_raids = (1..)
+            .zip(raids)
+            .map(|(id, raid)| LiveRaid { id, ..raid })
+            .collect();
+        Ok(())
+    }
+
+    /// + 이전 버전 SQLite 숙제표 가져오기 (1회만 적용)
+    // ? 기본 레이드는 카탈로그 기준을 유지하고, 없는 레이드를 가리키는 항목은 제외
+    pub fn import_legacy(&mut self, legacy: Homework) -> bool {
+        if self.legacy_imported {
+            return false;
+        }
+        self.legacy_imported = true;
+
+        for character in legacy.characters {
+            self.upsert_character(character);
+        }
+        for mut group in legacy.group_raids {
+            group
+                .raid_ids
+                .retain(|id| self.default_raids.iter().any(|r| r.raid_id == *id));
+            let _ = self.upsert_group_raid(group);
+        }
+
+        let mut next_id = self.live_raids.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+        for raid in legacy.live_raids {
+            if self.validate_live_raid(&raid).is_ok() {
+                self.live_raids.push(LiveRaid {
+                    id: next_id,
+                    ..raid
+                });
+                next_id += 1;
+            }
+        }
+        true
+    }
+
+    /// + 숙제 완료 여부 변경
+    pub fn set_live_raid_complete(&mut self, id: u32, complete: bool) -> Result<(), String> {
+        let raid = self
+            .live_raids
+            .iter_mut()
+            .find(|r| r.id == id)
+            .ok_or_else(|| format!("존재하지 않는 숙제입니다: {}", id))?;
+        raid.complete = complete;
+        Ok(())
+    }
+}
+
+// > 숙제표 도메인 모델
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+#[tauri::command]
+pub async fn get_homework() -> Result<Homework, String> {
+    let app_state = get_state().lock().await;
+    Ok(app_state.homework.clone())
+}
+
+#[tauri::command]
+pub async fn upsert_character(character: Character) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.upsert_character(character);
+    mark_dirty();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn remove_character(char_id: u32) -> Result<bool, String> {
+    let mut app_state = get_state().lock().await;
+    let removed = app_state.homework.remove_character(char_id);
+    mark_dirty();
+    Ok(removed)
+}
+
+#[tauri::command]
+pub async fn set_characters(characters: Vec<Character>) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.set_characters(characters)?;
+    mark_dirty();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn upsert_group_raid(group: RaidGroup) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.upsert_group_raid(group)?;
+    mark_dirty();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn remove_group_raid(raid_id: u32) -> Result<bool, String> {
+    let mut app_state = get_state().lock().await;
+    let removed = app_state.homework.remove_group_raid(raid_id);
+    mark_dirty();
+    Ok(removed)
+}
+
+#[tauri::command]
+pub async fn set_group_raids(groups: Vec<RaidGroup>) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.set_group_raids(groups)?;
+    mark_dirty();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_live_raids(char_id: u32, raids: Vec<LiveRaid>) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.set_live_raids(char_id, raids)?;
+    mark_dirty();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn replace_live_raids(raids: Vec<LiveRaid>) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.replace_live_raids(raids)?;
+    mark_dirty();
+    Ok(())
+}
+
+/// ? 이전 버전 SQLite 숙제표를 가져옴 (이미 가져왔다면 false)
+#[tauri::command]
+pub async fn import_legacy_homework(homework: Homework) -> Result<bool, String> {
+    let mut app_state = get_state().lock().await;
+    let imported = app_state.homework.import_legacy(homework);
+    if imported {
+        mark_dirty();
+    }
+    Ok(imported)
+}
+
+/// ? 이전 버전 SQLite 숙제표 가져오기를 이미 마쳤는지 확인
+#[tauri::command]
+pub async fn is_legacy_homework_imported() -> Result<bool, String> {
+    let app_state = get_state().lock().await;
+    Ok(app_state.homework.legacy_imported)
+}
+
+#[tauri::command]
+pub async fn set_live_raid_complete(id: u32, complete: bool) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.set_live_raid_complete(id, complete)?;
+    mark_dirty();
+    Ok(())
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn character(char_id: u32, priority: u32) -> Character {
+        Character {
+            char_id,
+            priority,
+            char_name: format!("캐릭터{}", char_id),
+            class: "바드".into(),
+            color: "slate".into(),
+        }
+    }
+
+    fn raid(raid_id: u32, reward: u32) -> Raid {
+        Raid {
+            raid_id,
+            priority: raid_id,
+            level: 1600,
+            raid_name: format!("레이드{}", raid_id),
+            difficulty: "노말".into(),
+            gate: "1".into(),
+            reward,
+            reset_type: 1,
+            retired: false,
+        }
+    }
+
+    fn group(raid_id: u32, raid_ids: Vec<u32>) -> RaidGroup {
+        RaidGroup {
+            raid: raid(raid_id, 0),
+            raid_ids,
+        }
+    }
+
+    fn live(char_id: u32, raid_type: RaidSource, raid_id: u32) -> LiveRaid {
+        LiveRaid {
+            id: 0,
+            char_id,
+            raid_type,
+            raid_id,
+            complete: false,
+            swap: 0,
+            reset_type: 1,
+        }
+    }
+
+    fn homework() -> Homework {
+        Homework {
+            characters: vec![character(1, 1), character(2, 2)],
+            default_raids: vec![raid(10, 500), raid(11, 700)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn upsert_character_adds_and_updates() {
+        let mut homework = homework();
+
+        homework.upsert_character(character(3, 0));
+        assert_eq!(homework.characters[0].char_id, 3); // ? priority 순 정렬
+
+        let mut renamed = character(1, 1);
+        renamed.char_name = "변경".into();
+        homework.upsert_character(renamed);
+        assert_eq!(homework.characters.len(), 3);
+        assert_eq!(homework.characters[1].char_name, "변경");
+    }
+
+    #[test]
+    fn remove_character_removes_its_live_raids() {
+        let mut homework = homework();
+        homework
+            .set_live_raids(1, vec![live(1, RaidSource::Default, 10)])
+            .unwrap();
+        homework
+            .set_live_raids(2, vec![live(2, RaidSource::Default, 11)])
+            .unwrap();
+
+        assert!(homework.remove_character(1));
+        assert!(!homework.remove_character(1));
+        assert_eq!(homework.characters.len(), 1);
+        assert!(homework.live_raids.iter().all(|r| r.char_id == 2));
+    }
+
+    #[test]
+    fn set_characters_rejects_duplicates_and_drops_removed() {
+        let mut homework = homework();
+        homework
+            .replace_live_raids(vec![
+                live(1, RaidSource::Default, 10),
+                live(2, RaidSource::Default, 10),
+            ])
+            .unwrap();
+
+        assert!(homework
+            .set_characters(vec![character(2, 1), character(2, 2)])
+            .is_err());
+        assert_eq!(homework.characters.len(), 2);
+
+        homework.set_characters(vec![character(2, 1)]).unwrap();
+        assert_eq!(homework.characters, vec![character(2, 1)]);
+        assert_eq!(homework.live_raids.len(), 1);
+        assert_eq!(homework.live_raids[0].char_id, 2);
+    }
+
+    #[test]
+    fn group_raid_reward_is_sum_of_default_raids() {
+        let mut homework = homework();
+
+        homework
+            .upsert_group_raid(group(100, vec![10, 11]))
+            .unwrap();
+        assert_eq!(homework.group_raids[0].raid.reward, 1200);
+
+        homework.upsert_group_raid(group(100, vec![11])).unwrap();
+        assert_eq!(homework.group_raids.len(), 1);
+        assert_eq!(homework.group_raids[0].raid.reward, 700);
+
+        homework.default_raids[1].reward = 300;
+        homework.refresh_group_rewards();
+        assert_eq!(homework.group_raids[0].raid.reward, 300);
+    }
+
+    #[test]
+    fn group_raid_with_missing_raid_is_rejected() {
+        let mut homework = homework();
+
+        assert!(homework
+            .upsert_group_raid(group(100, vec![10, 99]))
+            .is_err());
+        assert!(homework
+            .set_group_raids(vec![group(100, vec![99])])
+            .is_err());
+        assert!(homework.group_raids.is_empty());
+    }
+
+    #[test]
+    fn removing_group_raid_removes_its_live_raids() {
+        let mut homework = homework();
+        homework
+            .set_group_raids(vec![group(100, vec![10]), group(101, vec![11])])
+            .unwrap();
+        homework
+            .replace_live_raids(vec![
+                live(1, RaidSource::Group, 100),
+                live(1, RaidSource::Group, 101),
+                live(1, RaidSource::Default, 10),
+            ])
+            .unwrap();
+
+        assert!(homework.remove_group_raid(100));
+        assert!(!homework.remove_group_raid(100));
+        assert_eq!(homework.live_raids.len(), 2);
+
+        homework.set_group_raids(vec![]).unwrap();
+        assert_eq!(homework.live_raids.len(), 1);
+        assert_eq!(homework.live_raids[0].raid_type, RaidSource::Default);
+    }
+
+    #[test]
+    fn set_live_raids_validates_character_and_raid() {
+        let mut homework = homework();
+
+        assert!(homework
+            .set_live_raids(9, vec![live(9, RaidSource::Default, 10)])
+            .is_err());
+        assert!(homework
+            .set_live_raids(1, vec![live(2, RaidSource::Default, 10)])
+            .is_err());
+        assert!(homework
+            .set_live_raids(1, vec![live(1, RaidSource::Default, 99)])
+            .is_err());
+        assert!(homework
+            .set_live_raids(1, vec![live(1, RaidSource::Group, 10)])
+            .is_err());
+        assert!(homework.live_raids.is_empty());
+    }
+
+    #[test]
+    fn live_raid_ids_are_reissued() {
+        let mut homework = homework();
+        homework
+            .set_live_raids(1, vec![live(1, RaidSource::Default, 10)])
+            .unwrap();
+        homework
+            .set_live_raids(2, vec![live(2, RaidSource::Default, 10)])
+            .unwrap();
+        homework
+            .set_live_raids(1, vec![live(1, RaidSource::Default, 11)])
+            .unwrap();
+
+        let mut ids: Vec<u32> = homework.live_raids.iter().map(|r| r.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 2);
+
+        homework
+            .replace_live_raids(vec![
+                live(2, RaidSource::Default, 11),
+                live(1, RaidSource::Default, 10),
+            ])
+            .unwrap();
+        let ids: Vec<u32> = homework.live_raids.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn set_live_raid_complete_updates_or_errors() {
+        let mut homework = homework();
+        homework
+            .replace_live_raids(vec![live(1, RaidSource::Default, 10)])
+            .unwrap();
+
+        homework.set_live_raid_complete(1, true).unwrap();
+        assert!(homework.live_raids[0].complete);
+        assert!(homework.set_live_raid_complete(42, true).is_err());
+    }
+
+    #[test]
+    fn import_legacy_applies_once_and_skips_unknown_raids() {
+        let mut homework = homework();
+        homework.characters.clear();
+
+        let legacy = Homework {
+            characters: vec![character(1, 1)],
+            group_raids: vec![group(100, vec![10, 99])],
+            live_raids: vec![
+                live(1, RaidSource::Default, 10),
+                live(1, RaidSource::Default, 99),
+                live(1, RaidSource::Group, 100),
+                live(7, RaidSource::Default, 10),
+            ],
+            ..Default::default()
+        };
+
+        assert!(homework.import_legacy(legacy.clone()));
+        assert_eq!(homework.characters.len(), 1);
+        assert_eq!(homework.group_raids[0].raid_ids, vec![10]);
+        assert_eq!(homework.group_raids[0].raid.reward, 500);
+        assert_eq!(homework.live_raids.len(), 2);
+
+        assert!(!homework.import_legacy(legacy));
+        assert_eq!(homework.live_raids.len(), 2);
+    }
+}
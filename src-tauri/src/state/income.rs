@@ -0,0 +1,200 @@
+use super::homework::Homework;
+use super::reset::{last_reset_boundary, now_secs, reset_kind_at, ResetKind};
+use super::store::get_state;
+use serde::Serialize;
+
+// > 주간 골드 수입 계산
+// ? 2주 레이드(resetType 2)는 2주 초기화가 적용된 주에만 예상, 획득, 남은 골드에 포함
+// ? 2주 초기화가 없는 주에는 지난 주에 완료한 2주 레이드가 완료 상태로 남아 있으므로 제외
+// ? (예상 골드 = 획득 골드 + 남은 골드)
+
+/// * 캐릭터별 수입 구조체
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterIncome {
+    pub char_id: u32,
+    pub expected: u64,  // 이번 주 예상 골드
+    pub earned: u64,    // 완료한 관문 골드
+    pub remaining: u64, // 남은 관문 골드
+}
+
+/// * 주간 수입 구조체
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeProjection {
+    pub expected: u64,
+    pub earned: u64,
+    pub remaining: u64,
+    pub characters: Vec<CharacterIncome>,
+}
+
+/// + 숙제표 기준 주간 수입 계산
+// ? `biweekly_week`: 이번 주가 2주 초기화가 적용된 주인지 여부
+pub fn project_weekly_income(homework: &Homework, biweekly_week: bool) -> IncomeProjection {
+    let mut projection = IncomeProjection::default();
+
+    for character in &homework.characters {
+        let mut income = CharacterIncome {
+            char_id: character.char_id,
+            ..Default::default()
+        };
+
+        for live in homework
+            .live_raids
+            .iter()
+            .filter(|r| r.char_id == character.char_id)
+        {
+            let Some(raid) = homework.find_raid(live.raid_type, live.raid_id) else {
+                continue;
+            };
+            // ? 2주 초기화가 없는 주의 2주 레이드는 모든 합계에서 제외
+            if live.reset_type == 2 && !biweekly_week {
+                continue;
+            }
+
+            let reward = raid.reward as u64;
+            income.expected += reward;
+            if live.complete {
+                income.earned += reward;
+            } else {
+                income.remaining += reward;
+            }
+        }
+
+        projection.expected += income.expected;
+        projection.earned += income.earned;
+        projection.remaining += income.remaining;
+        projection.characters.push(income);
+    }
+
+    projection
+}
+
+// > 주간 골드 수입 계산
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+#[tauri::command]
+pub async fn get_weekly_income() -> Result<IncomeProjection, String> {
+    let app_state = get_state().lock().await;
+    let week_start = last_reset_boundary(now_secs());
+    let biweekly_week =
+        reset_kind_at(week_start, app_state.reset_state.biweekly_anchor) == ResetKind::TwoWeek;
+
+    Ok(project_weekly_income(&app_state.homework, biweekly_week))
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::homework::{Character, LiveRaid, Raid, RaidSource};
+
+    fn homework(raids: &[(u32, u8, bool)]) -> Homework {
+        Homework {
+            characters: vec![Character {
+                char_id: 1,
+                priority: 1,
+                char_name: "캐릭터".into(),
+                class: "바드".into(),
+                color: "slate".into(),
+            }],
+            default_raids: raids
+                .iter()
+                .map(|&(reward, reset_type, _)| Raid {
+                    raid_id: reward,
+                    priority: 1,
+                    level: 1600,
+                    raid_name: "레이드".into(),
+                    difficulty: "노말".into(),
+                    gate: "1".into(),
+                    reward,
+                    reset_type,
+                    retired: false,
+                })
+                .collect(),
+            live_raids: raids
+                .iter()
+                .enumerate()
+                .map(|(id, &(reward, reset_type, complete))| LiveRaid {
+                    id: id as u32 + 1,
+                    char_id: 1,
+                    raid_type: RaidSource::Default,
+                    raid_id: reward,
+                    complete,
+                    swap: 0,
+                    reset_type,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn weekly_raids_count_every_week() {
+        let homework = homework(&[(100, 1, true), (200, 1, false)]);
+
+        for biweekly_week in [true, false] {
+            let projection = project_weekly_income(&homework, biweekly_week);
+            assert_eq!(projection.expected, 300);
+            assert_eq!(projection.earned, 100);
+            assert_eq!(projection.remaining, 200);
+        }
+    }
+
+    #[test]
+    fn biweekly_raids_are_expected_only_in_reset_week() {
+        let homework = homework(&[(100, 2, true), (200, 2, false)]);
+
+        let projection = project_weekly_income(&homework, true);
+        assert_eq!(projection.expected, 300);
+        assert_eq!(projection.earned, 100);
+        assert_eq!(projection.remaining, 200);
+
+        // ? 지난 주에 완료한 2주 레이드를 이번 주 획득 골드로 다시 계산하지 않음
+        let projection = project_weekly_income(&homework, false);
+        assert_eq!(projection.expected, 0);
+        assert_eq!(projection.earned, 0);
+        assert_eq!(projection.remaining, 0);
+    }
+
+    #[test]
+    fn expected_is_earned_plus_remaining() {
+        let homework = homework(&[
+            (100, 1, true),
+            (200, 1, false),
+            (300, 2, true),
+            (400, 2, false),
+        ]);
+
+        for biweekly_week in [true, false] {
+            let projection = project_weekly_income(&homework, biweekly_week);
+            assert_eq!(
+                projection.expected,
+                projection.earned + projection.remaining,
+                "biweekly_week: {}",
+                biweekly_week
+            );
+        }
+
+        let projection = project_weekly_income(&homework, false);
+        assert_eq!(projection.earned, 100);
+        assert_eq!(projection.remaining, 200);
+    }
+
+    #[test]
+    fn totals_are_summed_per_character() {
+        let projection = project_weekly_income(&homework(&[(100, 1, true), (50, 2, false)]), true);
+
+        assert_eq!(
+            projection.characters,
+            vec![CharacterIncome {
+                char_id: 1,
+                expected: 150,
+                earned: 100,
+                remaining: 50,
+            }]
+        );
+    }
+}
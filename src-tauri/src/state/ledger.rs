@@ -0,0 +1,246 @@
+use super::persist::mark_dirty;
+use super::reset::{last_reset_boundary, now_secs};
+use super::store::get_state;
+use super::types::AppState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// > 골드 장부
+// ? AppState.gold는 현재 잔액, gold_ledger는 잔액이 변경된 기록
+
+/// ? 장부에 보관할 최대 기록 수 (오래된 기록부터 삭제)
+const MAX_ENTRIES: usize = 5000;
+
+/// * 골드 변경 사유
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum GoldSource {
+    #[default]
+    Manual, // 사용자가 직접 수정
+    RaidClear, // 숙제 완료/취소
+    Reset,     // 골드 초기화 (잔액을 0으로 비움)
+}
+
+/// * 골드 장부 기록 구조체
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GoldEntry {
+    pub id: u64,
+    pub timestamp: u64, // unix sec
+    pub delta: i64,
+    pub source: GoldSource,
+    pub char_id: Option<u32>,
+}
+
+/// * 캐릭터별 골드 합계 구조체
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterGold {
+    pub char_id: Option<u32>,
+    pub total: i64,
+}
+
+/// * 골드 장부 구조체
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct GoldLedger {
+    pub entries: Vec<GoldEntry>,
+    pub next_id: u64,
+}
+
+impl GoldLedger {
+    /// + 기록 추가
+    pub fn record(&mut self, delta: i64, source: GoldSource, char_id: Option<u32>, timestamp: u64) {
+        self.next_id += 1;
+        self.entries.push(GoldEntry {
+            id: self.next_id,
+            timestamp,
+            delta,
+            source,
+            char_id,
+        });
+
+        if self.entries.len() > MAX_ENTRIES {
+            let overflow = self.entries.len() - MAX_ENTRIES;
+            self.entries.drain(..overflow);
+        }
+    }
+
+    /// + `since` 이후 골드 변경 합계
+    pub fn total_since(&self, since: u64) -> i64 {
+        self.entries
+            .iter()
+            .filter(|e| e.timestamp >= since)
+            .map(|e| e.delta)
+            .sum()
+    }
+
+    /// + `since` 이후 캐릭터별 골드 변경 합계 (캐릭터 미지정 기록은 None)
+    pub fn totals_by_character(&self, since: u64) -> Vec<CharacterGold> {
+        let mut totals: BTreeMap<Option<u32>, i64> = BTreeMap::new();
+        for entry in self.entries.iter().filter(|e| e.timestamp >= since) {
+            *totals.entry(entry.char_id).or_default() += entry.delta;
+        }
+
+        totals
+            .into_iter()
+            .map(|(char_id, total)| CharacterGold { char_id, total })
+            .collect()
+    }
+
+    /// + 최근 기록부터 반환
+    pub fn recent(&self, limit: usize) -> Vec<GoldEntry> {
+        self.entries.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+impl AppState {
+    /// + 골드 잔액을 변경하고 장부에 기록 (실제 변경된 양만 기록)
+    pub fn apply_gold_delta(&mut self, delta: i64, source: GoldSource, char_id: Option<u32>) {
+        let new_gold = (self.gold as i64 + delta).clamp(0, u32::MAX as i64) as u32;
+        let applied = new_gold as i64 - self.gold as i64;
+        self.gold = new_gold;
+
+        if applied != 0 {
+            self.gold_ledger
+                .record(applied, source, char_id, now_secs());
+        }
+    }
+
+    /// + 마지막 기록을 취소하고 잔액 복원
+    pub fn undo_last_gold_entry(&mut self) -> Option<GoldEntry> {
+        let entry = self.gold_ledger.entries.pop()?;
+        self.gold = (self.gold as i64 - entry.delta).clamp(0, u32::MAX as i64) as u32;
+        Some(entry)
+    }
+}
+
+// > 골드 장부
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+#[tauri::command]
+pub async fn get_gold_history(limit: Option<usize>) -> Result<Vec<GoldEntry>, String> {
+    let app_state = get_state().lock().await;
+    Ok(app_state.gold_ledger.recent(limit.unwrap_or(100)))
+}
+
+/// ? 이번 주 (마지막 주간 초기화 이후) 골드 변경 합계
+#[tauri::command]
+pub async fn get_weekly_gold_total() -> Result<i64, String> {
+    let app_state = get_state().lock().await;
+    Ok(app_state
+        .gold_ledger
+        .total_since(last_reset_boundary(now_secs())))
+}
+
+/// ? 이번 주 (마지막 주간 초기화 이후) 캐릭터별 골드 변경 합계
+#[tauri::command]
+pub async fn get_gold_by_character() -> Result<Vec<CharacterGold>, String> {
+    let app_state = get_state().lock().await;
+    Ok(app_state
+        .gold_ledger
+        .totals_by_character(last_reset_boundary(now_secs())))
+}
+
+#[tauri::command]
+pub async fn undo_last_gold_entry() -> Result<u32, String> {
+    let mut app_state = get_state().lock().await;
+    if app_state.undo_last_gold_entry().is_none() {
+        return Err("취소할 골드 기록이 없습니다.".into());
+    }
+    mark_dirty();
+    Ok(app_state.gold)
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ? 2024-01-03(수) 06:00 KST 주간 초기화 시각
+    const RESET: u64 = 1_704_229_200;
+
+    fn state_with_gold(gold: u32) -> AppState {
+        AppState {
+            gold,
+            ..AppState::default()
+        }
+    }
+
+    #[test]
+    fn record_assigns_ids_and_drops_oldest() {
+        let mut ledger = GoldLedger::default();
+        for i in 0..MAX_ENTRIES + 2 {
+            ledger.record(1, GoldSource::Manual, None, i as u64);
+        }
+
+        assert_eq!(ledger.entries.len(), MAX_ENTRIES);
+        assert_eq!(ledger.entries[0].id, 3);
+        assert_eq!(ledger.entries.last().unwrap().id, MAX_ENTRIES as u64 + 2);
+        assert_eq!(ledger.recent(2)[0].id, MAX_ENTRIES as u64 + 2);
+    }
+
+    #[test]
+    fn apply_gold_delta_clamps_at_zero() {
+        let mut state = state_with_gold(100);
+
+        state.apply_gold_delta(-250, GoldSource::Manual, Some(1));
+        assert_eq!(state.gold, 0);
+        assert_eq!(state.gold_ledger.entries.len(), 1);
+        assert_eq!(state.gold_ledger.entries[0].delta, -100); // ? 실제 변경된 양만 기록
+
+        state.apply_gold_delta(-50, GoldSource::Manual, Some(1));
+        assert_eq!(state.gold, 0);
+        assert_eq!(state.gold_ledger.entries.len(), 1); // ? 변경이 없으면 기록하지 않음
+    }
+
+    #[test]
+    fn undo_restores_balance() {
+        let mut state = state_with_gold(100);
+        assert_eq!(state.undo_last_gold_entry(), None);
+        assert_eq!(state.gold, 100);
+
+        state.apply_gold_delta(500, GoldSource::RaidClear, Some(1));
+        state.apply_gold_delta(-200, GoldSource::Manual, None);
+
+        assert_eq!(state.undo_last_gold_entry().map(|e| e.delta), Some(-200));
+        assert_eq!(state.gold, 600);
+        assert_eq!(state.undo_last_gold_entry().map(|e| e.delta), Some(500));
+        assert_eq!(state.gold, 100);
+        assert_eq!(state.undo_last_gold_entry(), None);
+        assert_eq!(state.gold, 100);
+    }
+
+    #[test]
+    fn totals_start_at_reset_boundary() {
+        let mut ledger = GoldLedger::default();
+        ledger.record(1000, GoldSource::RaidClear, Some(1), RESET - 1); // ? 지난 주
+        ledger.record(300, GoldSource::RaidClear, Some(1), RESET);
+        ledger.record(200, GoldSource::RaidClear, Some(2), RESET + 60);
+        ledger.record(-50, GoldSource::Manual, None, RESET + 120);
+
+        let since = last_reset_boundary(RESET + 3600);
+        assert_eq!(since, RESET);
+        assert_eq!(ledger.total_since(since), 450);
+        assert_eq!(ledger.total_since(last_reset_boundary(RESET - 1)), 1450);
+        assert_eq!(
+            ledger.totals_by_character(since),
+            vec![
+                CharacterGold {
+                    char_id: None,
+                    total: -50
+                },
+                CharacterGold {
+                    char_id: Some(1),
+                    total: 300
+                },
+                CharacterGold {
+                    char_id: Some(2),
+                    total: 200
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use super::types::DEFAULT_GAME_TITLE;
+use crate::window_utils::title_match::title_prefix;
+use serde_json::{Map, Value};
+
+// > loaassist_state.json 마이그레이션
+// ? 새 버전 추가 시 CURRENT_SCHEMA_VERSION을 올리고 MIGRATIONS 끝에 함수를 추가
+
+/// ? 현재 AppState 스키마 버전
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// ? MIGRATIONS[n]은 버전 n 문서를 버전 n + 1 문서로 변환
+type Migration = fn(&mut Map<String, Value>) -> Result<(), String>;
+
+const MIGRATIONS: [Migration; CURRENT_SCHEMA_VERSION as usize] =
+    [migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// + 버전 0 → 1: schema_version 필드가 없던 문서
+// ? game_title이 비어있으면 기본값이 적용되도록 제거
+fn migrate_v0_to_v1(doc: &mut Map<String, Value>) -> Result<(), String> {
+    if let Some(Value::Object(settings)) = doc.get_mut("user_settings") {
+        if let Some(Value::Object(focus)) = settings.get_mut("auto_focus_settings") {
+            let empty_title =
+                matches!(focus.get("game_title"), Some(Value::String(t)) if t.trim().is_empty());
+            if empty_title {
+                focus.remove("game_title");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// + 버전 1 → 2: 창 이름 비교 방식(match_mode) 추가
+// ? 이전 버전은 전체 일치로 저장한 "LOST ARK (64-bit, DX11) v.3.5.7.1" 같은 제목이 남아있으므로
+// ? 시작 부분 비교(기본값)에서 클라이언트 버전이 바뀌어도 찾을 수 있도록 버전 번호를 잘라냄
+// ? 이전 기본값 "LOST ARK"는 브라우저 창 등도 일치하므로 새 기본값으로 변경
+fn migrate_v1_to_v2(doc: &mut Map<String, Value>) -> Result<(), String> {
+    let Some(Value::Object(settings)) = doc.get_mut("user_settings") else {
+        return Ok(());
+    };
+    let Some(Value::Object(focus)) = settings.get_mut("auto_focus_settings") else {
+        return Ok(());
+    };
+
+    let is_prefix_mode = match focus.get("match_mode") {
+        None => true,
+        Some(mode) => mode == "prefix",
+    };
+    if let (true, Some(Value::String(title))) = (is_prefix_mode, focus.get_mut("game_title")) {
+        *title = match title.trim() {
+            "LOST ARK" => DEFAULT_GAME_TITLE.to_string(),
+            other => title_prefix(other).to_string(),
+        };
+    }
+    Ok(())
+}
+
+/// + 문서의 스키마 버전 확인 (필드가 없으면 버전 0)
+fn schema_version_of(doc: &Map<String, Value>) -> Result<u32, String> {
+    match doc.get("schema_version") {
+        None => Ok(0),
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| format!("잘못된 schema_version: {}", value)),
+    }
+}
+
+/// + 이전 버전 문서를 현재 버전까지 단계별로 변환
+pub fn migrate(mut value: Value) -> Result<Value, String> {
+    let doc = value
+        .as_object_mut()
+        .ok_or_else(|| "상태 파일이 JSON 객체가 아닙니다".to_string())?;
+
+    let version = schema_version_of(doc)?;
+    if version > CURRENT_SCHEMA_VERSION {
+        // ? 상위 버전 문서는 변환하지 않고 그대로 읽음 (모르는 필드는 무시, 덮어쓰기는 store에서 막음)
+        println!(
+            "⚠️ 상위 스키마 버전 상태 파일: v{} (현재: v{})",
+            version, CURRENT_SCHEMA_VERSION
+        );
+        return Ok(value);
+    }
+
+    for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        migration(doc)
+            .map_err(|e| format!("마이그레이션 실패 (v{} → v{}): {}", from, from + 1, e))?;
+        doc.insert("schema_version".to_string(), Value::from(from + 1));
+        println!("🔧 상태 파일 마이그레이션: v{} → v{}", from, from + 1);
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn document_without_version_is_migrated() {
+        let migrated = migrate(json!({
+            "user_settings": { "auto_focus_settings": { "game_title": " " } }
+        }))
+        .unwrap();
+
+        assert_eq!(migrated["schema_version"], json!(CURRENT_SCHEMA_VERSION));
+        assert!(migrated["user_settings"]["auto_focus_settings"]
+            .get("game_title")
+            .is_none());
+    }
+
+    #[test]
+    fn versioned_title_is_cut_to_prefix() {
+        let migrated = migrate(json!({
+            "schema_version": 1,
+            "user_settings": {
+                "auto_focus_settings": { "game_title": "LOST ARK (64-bit, DX11) v.3.5.7.1" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            migrated["user_settings"]["auto_focus_settings"]["game_title"],
+            json!("LOST ARK (")
+        );
+    }
+
+    #[test]
+    fn old_default_title_is_made_stricter() {
+        let migrated = migrate(json!({
+            "schema_version": 1,
+            "user_settings": { "auto_focus_settings": { "game_title": "LOST ARK" } }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            migrated["user_settings"]["auto_focus_settings"]["game_title"],
+            json!(DEFAULT_GAME_TITLE)
+        );
+    }
+
+    #[test]
+    fn title_is_kept_for_other_match_modes() {
+        let title = "LOST ARK (64-bit, DX11) v.3.5.7.1";
+        let migrated = migrate(json!({
+            "schema_version": 1,
+            "user_settings": {
+                "auto_focus_settings": { "game_title": title, "match_mode": "exact" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            migrated["user_settings"]["auto_focus_settings"]["game_title"],
+            json!(title)
+        );
+    }
+
+    #[test]
+    fn newer_document_is_kept_as_is() {
+        let newer = json!({ "schema_version": CURRENT_SCHEMA_VERSION + 1, "new_field": true });
+        assert_eq!(migrate(newer.clone()).unwrap(), newer);
+    }
+
+    #[test]
+    fn invalid_document_is_rejected() {
+        assert!(migrate(json!([])).is_err());
+        assert!(migrate(json!({ "schema_version": "1" })).is_err());
+    }
+}
@@ -1,2 +1,10 @@
+pub mod catalogue;
+pub mod error;
+pub mod homework;
+pub mod income;
+pub mod ledger;
+pub mod migrations;
+pub mod persist;
+pub mod reset;
 pub mod store;
 pub mod types;
@@ -0,0 +1,95 @@
+use super::error::StoreError;
+use super::store::{get_state, save_state};
+use once_cell::sync::Lazy;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
+use tauri::{AppHandle, Emitter};
+use tokio::{
+    sync::Notify,
+    time::{sleep, Duration},
+};
+
+// > 상태 저장 워커
+// ? Command는 상태 변경 후 mark_dirty()만 호출하고 바로 반환
+// ? 실제 파일 저장은 워커가 FLUSH_INTERVAL 마다 한 번으로 모아서 처리
+
+pub const SAVE_FAILED_EVENT: &str = "state://save-failed";
+
+/// ? 변경사항을 모아서 저장하는 간격
+const FLUSH_INTERVAL: Duration = Duration::from_millis(500);
+
+static DIRTY: AtomicBool = AtomicBool::new(false);
+static DIRTY_NOTIFY: Lazy<Notify> = Lazy::new(Notify::new);
+/// ? 워커와 종료 시 저장이 동시에 파일을 쓰지 않도록 직렬화
+static SAVE_LOCK: Mutex<()> = Mutex::new(());
+
+/// + 상태가 변경되었음을 표시 (저장 예약)
+pub fn mark_dirty() {
+    DIRTY.store(true, Ordering::SeqCst);
+    DIRTY_NOTIFY.notify_one();
+}
+
+/// + 변경된 상태가 있으면 즉시 저장 (blocking)
+pub fn flush_blocking() -> Result<(), StoreError> {
+    let _guard = SAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if !DIRTY.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    // ? 저장 중 상태 락을 잡고 있지 않도록 복사본으로 저장
+    let snapshot = get_state().blocking_lock().clone();
+    save_state(&snapshot).inspect_err(|_| {
+        DIRTY.store(true, Ordering::SeqCst); // ? 실패 시 다음 주기에 재시도
+    })
+}
+
+/// + 변경된 상태가 있으면 즉시 저장 (async Command 등 tokio 런타임 안에서 사용)
+// ? 런타임 안에서 blocking_lock()을 호출하면 panic이 발생하므로 상태는 lock().await로 복사하고
+// ? 파일 쓰기만 blocking 스레드에서 실행
+pub async fn flush() -> Result<(), StoreError> {
+    if !DIRTY.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let snapshot = get_state().lock().await.clone();
+    tokio::task::spawn_blocking(move || {
+        let _guard = SAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        if !DIRTY.swap(false, Ordering::SeqCst) {
+            return Ok(()); // ? 그 사이 워커가 저장함
+        }
+        save_state(&snapshot).inspect_err(|_| {
+            DIRTY.store(true, Ordering::SeqCst);
+        })
+    })
+    .await
+    .map_err(|e| StoreError::Io(std::io::Error::other(e)))?
+}
+
+/// + 상태 저장 워커 시작
+pub fn start_persist_worker(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            DIRTY_NOTIFY.notified().await;
+
+            // ? 연속된 변경사항을 한 번의 저장으로 모음
+            sleep(FLUSH_INTERVAL).await;
+
+            let result = match tokio::task::spawn_blocking(flush_blocking).await {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(e) => {
+                    DIRTY.store(true, Ordering::SeqCst); // ? 저장 작업이 중단되었으므로 다음 주기에 재시도
+                    Err(format!("저장 작업 중단: {}", e))
+                }
+            };
+
+            if let Err(e) = result {
+                eprintln!("❌ 상태 저장 실패: {}", e);
+                let _ = app.emit(SAVE_FAILED_EVENT, e);
+            }
+        }
+    });
+}
+
+// > 상태 저장 워커
@@ -0,0 +1,304 @@
+use super::homework::Homework;
+use super::persist::mark_dirty;
+use super::store::get_state;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+// > 주간 초기화 스케줄러
+// ? 로스트아크 주간 초기화: 매주 수요일 06:00 (KST)
+
+pub const RESET_EVENT: &str = "homework://reset";
+
+const WEEK_SECS: u64 = 7 * 24 * 60 * 60;
+/// ? 기준 초기화 시각: 1970-01-07(수) 06:00 KST = 1970-01-06 21:00 UTC
+const RESET_ANCHOR: u64 = 6 * 24 * 60 * 60 - 3 * 60 * 60;
+/// ? 절전 모드 등으로 타이머가 밀리는 경우를 대비한 최대 대기 시간
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// * 초기화 종류 (WorkResetModal의 "week", "twoWeek"와 동일)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ResetKind {
+    Week,    // resetType 1 숙제만 초기화
+    TwoWeek, // 모든 숙제 초기화
+}
+
+/// * 초기화 상태 구조체
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ResetState {
+    pub last_reset_at: u64,   // 마지막으로 적용된 초기화 시각 (unix sec)
+    pub biweekly_anchor: u64, // 2주 초기화가 적용되는 기준 초기화 시각 (unix sec)
+}
+
+impl Default for ResetState {
+    fn default() -> Self {
+        Self {
+            last_reset_at: 0,
+            biweekly_anchor: RESET_ANCHOR,
+        }
+    }
+}
+
+/// * 초기화 이벤트 payload
+#[derive(Serialize, Clone, Debug)]
+pub struct ResetEvent {
+    pub kind: ResetKind,
+    pub reset_at: u64,
+}
+
+/// * 초기화 정보 반환 구조체
+#[derive(Serialize, Clone, Debug)]
+pub struct ResetInfo {
+    pub last_reset_at: u64,
+    pub next_reset_at: u64,
+    pub next_kind: ResetKind,
+}
+
+/// + 현재 시각 (unix sec)
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// + `now` 시점에서 가장 최근에 지난 초기화 시각
+pub fn last_reset_boundary(now: u64) -> u64 {
+    if now < RESET_ANCHOR {
+        return RESET_ANCHOR;
+    }
+    RESET_ANCHOR + (now - RESET_ANCHOR) / WEEK_SECS * WEEK_SECS
+}
+
+/// + `now` 이후 다음 초기화 시각
+pub fn next_reset_boundary(now: u64) -> u64 {
+    if now < RESET_ANCHOR {
+        return RESET_ANCHOR;
+    }
+    last_reset_boundary(now) + WEEK_SECS
+}
+
+/// + 해당 초기화 시각이 2주 초기화인지 확인
+pub fn is_biweekly_boundary(boundary: u64, anchor: u64) -> bool {
+    boundary.abs_diff(anchor) / WEEK_SECS % 2 == 0
+}
+
+/// + 해당 초기화 시각에 적용될 초기화 종류
+pub fn reset_kind_at(boundary: u64, anchor: u64) -> ResetKind {
+    if is_biweekly_boundary(boundary, anchor) {
+        ResetKind::TwoWeek
+    } else {
+        ResetKind::Week
+    }
+}
+
+/// + `last_reset_at` 이후 `now`까지 놓친 초기화 계산
+// ? 여러 번 놓쳤다면 그 사이에 2주 초기화가 하나라도 있으면 TwoWeek
+pub fn pending_reset(last_reset_at: u64, now: u64, anchor: u64) -> Option<(ResetKind, u64)> {
+    let latest = last_reset_boundary(now);
+    if now < RESET_ANCHOR || latest <= last_reset_at {
+        return None;
+    }
+
+    let first = next_reset_boundary(last_reset_at);
+    let kind = if latest > first {
+        // ? 연속된 두 초기화 중 하나는 반드시 2주 초기화
+        ResetKind::TwoWeek
+    } else {
+        reset_kind_at(first, anchor)
+    };
+
+    Some((kind, latest))
+}
+
+impl Homework {
+    /// + 숙제 초기화 적용
+    pub fn apply_reset(&mut self, kind: ResetKind) {
+        for raid in &mut self.live_raids {
+            if kind == ResetKind::TwoWeek || raid.reset_type == 1 {
+                raid.complete = false;
+            }
+        }
+    }
+}
+
+/// + 놓친 초기화가 있다면 적용 후 이벤트 발생
+pub async fn apply_missed_resets(app: &AppHandle) {
+    let now = now_secs();
+    let mut app_state = get_state().lock().await;
+    let reset_state = &app_state.reset_state;
+
+    // ? 처음 실행이라면 초기화 없이 기준 시각만 기록
+    if reset_state.last_reset_at == 0 {
+        app_state.reset_state.last_reset_at = last_reset_boundary(now);
+        mark_dirty();
+        return;
+    }
+
+    let Some((kind, reset_at)) =
+        pending_reset(reset_state.last_reset_at, now, reset_state.biweekly_anchor)
+    else {
+        return;
+    };
+
+    app_state.homework.apply_reset(kind);
+    app_state.reset_state.last_reset_at = reset_at;
+    mark_dirty();
+    drop(app_state); // ? 상태 락 해제
+
+    println!("🔄 숙제 자동 초기화 적용: {:?} ({})", kind, reset_at);
+    let _ = app.emit(RESET_EVENT, ResetEvent { kind, reset_at });
+}
+
+/// + 주간 초기화 스케줄러 시작 (실행 시 + 실행 중 초기화 시각마다)
+pub fn start_reset_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            apply_missed_resets(&app).await;
+
+            let now = now_secs();
+            let wait = Duration::from_secs(next_reset_boundary(now) - now + 1);
+            tokio::time::sleep(wait.min(MAX_SLEEP)).await;
+        }
+    });
+}
+
+// > 주간 초기화 스케줄러
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+#[tauri::command]
+pub async fn get_reset_info() -> Result<ResetInfo, String> {
+    let app_state = get_state().lock().await;
+    let next_reset_at = next_reset_boundary(now_secs());
+
+    Ok(ResetInfo {
+        last_reset_at: app_state.reset_state.last_reset_at,
+        next_reset_at,
+        next_kind: reset_kind_at(next_reset_at, app_state.reset_state.biweekly_anchor),
+    })
+}
+
+/// ? 숙제 초기화 Modal에서 직접 초기화 ("week", "twoWeek")
+// ? 이번 주 초기화를 직접 적용했으므로 자동 초기화가 다시 적용되지 않도록 기록
+#[tauri::command]
+pub async fn reset_homework(kind: ResetKind) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.homework.apply_reset(kind);
+    let current = last_reset_boundary(now_secs());
+    let reset_state = &mut app_state.reset_state;
+    reset_state.last_reset_at = reset_state.last_reset_at.max(current);
+    mark_dirty();
+    Ok(())
+}
+
+/// ? 이번 주 초기화가 2주 초기화였는지 사용자가 지정 (2주 주기 보정)
+#[tauri::command]
+pub async fn set_biweekly_reset_week(is_biweekly: bool) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    let current = last_reset_boundary(now_secs());
+
+    app_state.reset_state.biweekly_anchor = if is_biweekly {
+        current
+    } else {
+        current - WEEK_SECS
+    };
+    mark_dirty();
+    Ok(())
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 60 * 60;
+    /// ? 2024-01-03(수) 06:00 KST = 2024-01-02 21:00 UTC (RESET_ANCHOR와 홀수 주 차이)
+    const WED: u64 = 1_704_229_200;
+
+    #[test]
+    fn boundaries_around_wednesday_six_kst() {
+        let cases = [
+            // (now, last, next)
+            (WED - 1, WED - WEEK_SECS, WED),
+            (WED, WED, WED + WEEK_SECS),
+            (WED + 1, WED, WED + WEEK_SECS),
+            (WED + 3 * 24 * HOUR, WED, WED + WEEK_SECS),
+            (WED + WEEK_SECS - 1, WED, WED + WEEK_SECS),
+            (RESET_ANCHOR - 1, RESET_ANCHOR, RESET_ANCHOR),
+        ];
+
+        for (now, last, next) in cases {
+            assert_eq!(last_reset_boundary(now), last, "last at {}", now);
+            assert_eq!(next_reset_boundary(now), next, "next at {}", now);
+        }
+    }
+
+    #[test]
+    fn biweekly_parity_follows_anchor() {
+        let cases = [
+            (WED, WED, true),
+            (WED + WEEK_SECS, WED, false),
+            (WED + 2 * WEEK_SECS, WED, true),
+            (WED - WEEK_SECS, WED, false),
+            (WED - 2 * WEEK_SECS, WED, true),
+            (WED, RESET_ANCHOR, false), // ? RESET_ANCHOR와 홀수 주 차이
+            (WED + WEEK_SECS, RESET_ANCHOR, true),
+        ];
+
+        for (boundary, anchor, expected) in cases {
+            assert_eq!(
+                is_biweekly_boundary(boundary, anchor),
+                expected,
+                "{} / {}",
+                boundary,
+                anchor
+            );
+        }
+    }
+
+    #[test]
+    fn pending_reset_counts_missed_boundaries() {
+        let anchor = WED; // ? WED가 2주 초기화
+        let cases = [
+            // (last_reset_at, now, expected)
+            (WED, WED + WEEK_SECS - 1, None), // ? 다음 초기화 직전
+            (WED - WEEK_SECS, WED - 1, None),
+            (WED - WEEK_SECS, WED, Some((ResetKind::TwoWeek, WED))), // ? 1번 놓침 (2주)
+            (
+                WED,
+                WED + WEEK_SECS,
+                Some((ResetKind::Week, WED + WEEK_SECS)),
+            ), // ? 1번 놓침 (1주)
+            (
+                WED,
+                WED + 2 * WEEK_SECS + HOUR,
+                Some((ResetKind::TwoWeek, WED + 2 * WEEK_SECS)),
+            ), // ? 2번 놓침
+            (
+                WED - WEEK_SECS,
+                WED + WEEK_SECS,
+                Some((ResetKind::TwoWeek, WED + WEEK_SECS)),
+            ), // ? 2번 놓침 (마지막은 1주 초기화)
+            (
+                WED,
+                WED + 5 * WEEK_SECS,
+                Some((ResetKind::TwoWeek, WED + 5 * WEEK_SECS)),
+            ),
+            (WED + WEEK_SECS, WED + 1, None), // ? 기록이 현재보다 이후 (시계 변경)
+        ];
+
+        for (last_reset_at, now, expected) in cases {
+            assert_eq!(
+                pending_reset(last_reset_at, now, anchor),
+                expected,
+                "{} -> {}",
+                last_reset_at,
+                now
+            );
+        }
+    }
+}
@@ -1,16 +1,35 @@
-use super::types::{AppState, UserSettings};
+use super::error::StoreError;
+use super::ledger::GoldSource;
+use super::migrations::{migrate, CURRENT_SCHEMA_VERSION};
+use super::persist::mark_dirty;
+use super::types::{AppState, DockState, UserSettings};
+use crate::dock::dock_manager::is_auto_hidden;
 use crate::window_utils::auto_focus_shift;
 use dirs::data_dir;
+use serde_json::Value;
 use std::{
-    fs::{self, File},
-    io::Read,
-    path::Path,
-    sync::{Arc, OnceLock},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
 };
 use tauri::{AppHandle, Manager, PhysicalPosition};
-use tokio::sync::Mutex;
+use tempfile::NamedTempFile;
+use tokio::sync::{watch, Mutex};
 
 static STATE: OnceLock<Arc<Mutex<AppState>>> = OnceLock::new();
+/// ? 상위 버전 프로그램이 저장한 상태 파일을 읽었는지 여부 (롤백 등, 덮어쓰지 않도록 저장 중지)
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+/// ? 읽기 전용이라 저장을 건너뛴다는 안내를 이미 출력했는지 여부 (1회만 출력)
+static READ_ONLY_WARNED: AtomicBool = AtomicBool::new(false);
+/// ? 기존 상태 파일을 불러왔는지 여부 (처음 설치와 이전 버전에서 업데이트 구분)
+static LOADED_EXISTING: AtomicBool = AtomicBool::new(false);
+/// ? 사용자 설정 변경 알림 (설정을 주기적으로 다시 읽지 않고 변경될 때만 확인)
+static SETTINGS_CHANGED: OnceLock<watch::Sender<()>> = OnceLock::new();
 
 // > 상태 관리 함수
 
@@ -24,28 +43,91 @@ pub fn get_state() -> &'static Arc<Mutex<AppState>> {
     STATE.get().expect("State is not initialized")
 }
 
+fn settings_channel() -> &'static watch::Sender<()> {
+    SETTINGS_CHANGED.get_or_init(|| watch::channel(()).0)
+}
+
+/// + 사용자 설정 변경 구독 (set_user_settings 호출마다 changed() 완료)
+pub fn subscribe_settings() -> watch::Receiver<()> {
+    settings_channel().subscribe()
+}
+
 /// + 프로그램의 AppData 폴더 경로 가져오기
 pub fn get_app_data_dir() -> Option<String> {
-    let app_data_path = data_dir();
-    app_data_path.map(|dir| {
-        Path::new(&dir)
-            .join("com.loaassist.app")
-            .join("loaassist_state.json")
-            .to_string_lossy()
-            .to_string()
-    })
+    get_state_file_path().map(|path| path.to_string_lossy().to_string())
+}
+
+/// + 상태 파일(loaassist_state.json) 경로 가져오기
+pub fn get_state_file_path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join("com.loaassist.app").join("loaassist_state.json"))
+}
+
+/// + 상태 파일의 롤링 백업(.bak) 경로
+fn backup_file_path(file_path: &Path) -> PathBuf {
+    file_path.with_extension("json.bak")
+}
+
+/// + JSON 문자열을 현재 버전의 상태로 변환 (필요 시 마이그레이션)
+fn parse_state(contents: &str) -> Result<AppState, String> {
+    let value: Value =
+        serde_json::from_str(contents).map_err(|e| format!("JSON 파싱 실패: {}", e))?;
+    let migrated = migrate(value)?;
+    serde_json::from_value(migrated).map_err(|e| format!("상태 변환 실패: {}", e))
+}
+
+/// + 읽을 수 없는 상태 파일의 백업 경로
+// ? .bak 파일도 같은 규칙이 되도록 확장자 대신 파일 이름 뒤에 붙임
+fn corrupt_backup_path(file_path: &Path, timestamp: u64) -> PathBuf {
+    let file_name = file_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    file_path.with_file_name(format!("{file_name}.corrupt-{timestamp}"))
+}
+
+/// + 읽을 수 없는 상태 파일을 백업 (다음 save_state에 덮어쓰이지 않도록)
+fn backup_unreadable_state(file_path: &Path) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let backup_path = corrupt_backup_path(file_path, timestamp);
+
+    match fs::rename(file_path, &backup_path) {
+        Ok(_) => println!("⚠️ 읽을 수 없는 상태 파일 백업: {:?}", backup_path),
+        Err(e) => eprintln!("상태 파일 백업 실패: {:?}", e),
+    }
+}
+
+/// + 파일 하나를 읽어서 상태로 변환 (읽을 수 없으면 백업 후 None)
+fn load_state_file(file_path: &Path) -> Option<AppState> {
+    let contents = fs::read_to_string(file_path).ok()?;
+
+    match parse_state(&contents) {
+        Ok(state) => {
+            if state.schema_version > CURRENT_SCHEMA_VERSION {
+                println!("⚠️ 상위 버전 상태 파일은 읽기 전용으로 사용 (저장하지 않음)");
+                READ_ONLY.store(true, Ordering::SeqCst);
+            }
+            Some(state)
+        }
+        Err(e) => {
+            eprintln!("❌ 상태 파일 불러오기 실패 {:?}: {}", file_path, e);
+            backup_unreadable_state(file_path);
+            None
+        }
+    }
 }
 
 /// + JSON 파일에서 상태 불러오기
 pub fn load_state() -> AppState {
-    if let Some(file_path) = get_app_data_dir() {
-        if let Ok(mut file) = File::open(file_path) {
-            let mut contents = String::new();
-            if file.read_to_string(&mut contents).is_ok() {
-                if let Ok(state) = serde_json::from_str::<AppState>(&contents) {
-                    return state;
-                }
-            }
+    if let Some(file_path) = get_state_file_path() {
+        // ? 상태 파일을 읽을 수 없다면 마지막 .bak 파일로 복구
+        let state =
+            load_state_file(&file_path).or_else(|| load_state_file(&backup_file_path(&file_path)));
+        if let Some(state) = state {
+            LOADED_EXISTING.store(true, Ordering::SeqCst);
+            return state;
         }
     }
 
@@ -53,13 +135,39 @@ pub fn load_state() -> AppState {
     AppState::default()
 }
 
-// + 상태를 JSON 파일에 저장
-pub fn save_state(state: &AppState) {
-    if let Some(file_path) = get_app_data_dir() {
-        if let Ok(json) = serde_json::to_string_pretty(state) {
-            let _ = fs::write(file_path, json);
+/// + 실행 시 기존 상태 파일(.bak 포함)을 불러왔는지 여부
+pub fn loaded_existing_state() -> bool {
+    LOADED_EXISTING.load(Ordering::SeqCst)
+}
+
+/// + 상태를 JSON 파일에 저장
+// ? 같은 폴더의 임시 파일에 기록 → fsync → rename 으로 교체하여
+// ? 저장 도중 프로그램이 종료되어도 잘린 파일이 남지 않도록 함
+pub fn save_state(state: &AppState) -> Result<(), StoreError> {
+    if READ_ONLY.load(Ordering::SeqCst) {
+        if !READ_ONLY_WARNED.swap(true, Ordering::SeqCst) {
+            println!("⚠️ 상위 버전 상태 파일을 보존하기 위해 변경 사항을 저장하지 않습니다.");
         }
+        return Ok(()); // ? 상위 버전 상태 파일 보존
+    }
+
+    let file_path = get_state_file_path().ok_or(StoreError::NoAppDataDir)?;
+    let dir = file_path.parent().ok_or(StoreError::NoAppDataDir)?;
+    fs::create_dir_all(dir)?;
+
+    let json = serde_json::to_string_pretty(state)?;
+
+    let mut temp_file = NamedTempFile::new_in(dir)?;
+    temp_file.write_all(json.as_bytes())?;
+    temp_file.as_file().sync_all()?;
+
+    // ? 교체 전 기존 파일을 .bak 으로 보관
+    if file_path.exists() {
+        fs::copy(&file_path, backup_file_path(&file_path))?;
     }
+
+    temp_file.persist(&file_path).map_err(|e| e.error)?;
+    Ok(())
 }
 
 // > 상태 관리 함수
@@ -73,27 +181,36 @@ pub async fn get_gold() -> Result<u32, String> {
 }
 
 #[tauri::command]
-pub async fn set_gold(value: u32) -> Result<u32, String> {
+pub async fn set_gold(value: u32, source: Option<GoldSource>) -> Result<u32, String> {
     let mut app_state = get_state().lock().await;
-    app_state.gold = value;
-    save_state(&app_state);
+    let delta = value as i64 - app_state.gold as i64;
+    app_state.apply_gold_delta(delta, source.unwrap_or_default(), None);
+    mark_dirty();
     Ok(app_state.gold)
 }
 
 #[tauri::command]
-pub async fn increment_gold(value: u32) -> Result<u32, String> {
+pub async fn increment_gold(
+    value: u32,
+    source: Option<GoldSource>,
+    char_id: Option<u32>,
+) -> Result<u32, String> {
     let mut app_state = get_state().lock().await;
-    app_state.gold += value;
-    save_state(&app_state);
+    app_state.apply_gold_delta(value as i64, source.unwrap_or_default(), char_id);
+    mark_dirty();
     Ok(app_state.gold)
 }
 
 #[tauri::command]
-pub async fn decrement_gold(value: u32) -> Result<u32, String> {
+pub async fn decrement_gold(
+    value: u32,
+    source: Option<GoldSource>,
+    char_id: Option<u32>,
+) -> Result<u32, String> {
     let mut app_state = get_state().lock().await;
-    // ? underflow 에러 방지
-    app_state.gold = app_state.gold.saturating_sub(value);
-    save_state(&app_state);
+    // ? underflow 에러 방지 (apply_gold_delta에서 0으로 제한)
+    app_state.apply_gold_delta(-(value as i64), source.unwrap_or_default(), char_id);
+    mark_dirty();
     Ok(app_state.gold)
 }
 
@@ -106,10 +223,16 @@ pub async fn get_user_settings() -> Result<UserSettings, String> {
 #[tauri::command]
 pub async fn set_user_settings(app: AppHandle, settings: UserSettings) -> Result<(), String> {
     let mut app_state = get_state().lock().await;
+    if settings.anchor_enabled && !app_state.user_settings.anchor_enabled {
+        app_state.window_anchor = None; // ? 따라가기를 켠 시점의 위치를 새 기준으로 사용
+    }
     app_state.user_settings = settings.clone(); // ? `clone()`으로 복사
-    save_state(&app_state);
+    mark_dirty();
+    drop(app_state); // ? 알림을 받은 작업이 바로 설정을 읽을 수 있도록 먼저 잠금 해제
+    settings_channel().send_replace(());
 
-    if settings.auto_focus_enabled {
+    // ? 자동 숨기기도 추적 스레드의 마우스 위치를 사용
+    if settings.auto_focus_enabled || settings.dock_settings.auto_hide {
         if let Some(window) = app.get_webview_window("main") {
             auto_focus_shift::start_mouse_tracking(window);
         }
@@ -128,24 +251,46 @@ pub async fn get_position() -> Result<PhysicalPosition<i32>, String> {
 
 #[tauri::command]
 pub async fn set_position(new_position: PhysicalPosition<i32>) -> Result<(), String> {
+    if is_auto_hidden() {
+        return Ok(()); // ? 자동 숨기기로 가장자리 밖에 있는 위치는 저장하지 않음
+    }
+
     let mut app_state = get_state().lock().await;
     app_state.window_position = new_position;
-    save_state(&app_state);
+    mark_dirty();
     Ok(())
 }
 
+/// + 마지막으로 저장된 도킹 상태 (Frontend가 dock://changed 이벤트보다 늦게 준비된 경우 조회용)
 #[tauri::command]
-pub async fn get_default_tab() -> Result<String, String> {
+pub async fn get_dock_state() -> Result<DockState, String> {
     let app_state = get_state().lock().await;
-    Ok(app_state.user_settings.default_tab.clone())
+    Ok(app_state.dock_state.clone())
 }
 
 #[tauri::command]
-pub async fn set_game_title(title: String) -> Result<(), String> {
-    let mut app_state = get_state().lock().await;
-    app_state.user_settings.auto_focus_settings.game_title = title;
-    save_state(&app_state);
-    Ok(())
+pub async fn get_default_tab() -> Result<String, String> {
+    let app_state = get_state().lock().await;
+    Ok(app_state.user_settings.default_tab.clone())
 }
 
 // > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrupt_backup_keeps_file_name() {
+        let dir = Path::new("data");
+
+        assert_eq!(
+            corrupt_backup_path(&dir.join("loaassist_state.json"), 42),
+            dir.join("loaassist_state.json.corrupt-42")
+        );
+        assert_eq!(
+            corrupt_backup_path(&dir.join("loaassist_state.json.bak"), 42),
+            dir.join("loaassist_state.json.bak.corrupt-42")
+        );
+    }
+}
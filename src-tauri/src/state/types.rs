@@ -1,3 +1,7 @@
+use super::homework::Homework;
+use super::ledger::GoldLedger;
+use super::migrations::CURRENT_SCHEMA_VERSION;
+use super::reset::ResetState;
 use serde::{Deserialize, Serialize};
 use tauri::PhysicalPosition;
 
@@ -5,17 +9,31 @@ use tauri::PhysicalPosition;
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(default)]
 pub struct AppState {
+    pub schema_version: u32,                    // 상태 파일 스키마 버전
     pub gold: u32,                              // 골드 상태
+    pub gold_ledger: GoldLedger,                // 골드 변경 기록
     pub user_settings: UserSettings,            // 사용자 설정
     pub window_position: PhysicalPosition<i32>, // 윈도우 위치 상태
+    pub dock_state: DockState,                  // 윈도우 도킹 상태
+    pub window_anchor: Option<WindowAnchor>,    // 게임 창 기준 위치 (따라가기 모드)
+    pub homework: Homework,                     // 숙제표 상태
+    pub reset_state: ResetState,                // 주간 초기화 상태
+    pub last_seen_version: Option<String>,      // 변경 사항을 마지막으로 확인한 버전
 }
 
 impl Default for AppState {
     fn default() -> Self {
         Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
             gold: 0,
+            gold_ledger: GoldLedger::default(),
             user_settings: UserSettings::default(),
             window_position: PhysicalPosition { x: 100, y: 100 },
+            dock_state: DockState::default(),
+            window_anchor: None,
+            homework: Homework::default(),
+            reset_state: ResetState::default(),
+            last_seen_version: None,
         }
     }
 }
@@ -25,6 +43,7 @@ impl Default for AppState {
 #[serde(default)]
 pub struct UserSettings {
     pub update_check_enabled: bool,         // 업데이트 확인 여부
+    pub update_check_interval: u64,         // 백그라운드 업데이트 확인 주기 (min)
     pub theme: String,                      // 프로그램 테마
     pub class_image: bool,                  // 클래스 이미지 사용 여부
     pub folded_opacity_enabled: bool,       // 창 접었을 때 투명해지는지 여부
@@ -32,15 +51,20 @@ pub struct UserSettings {
     pub auto_focus_enabled: bool,           // Auto Focus 기능 사용 여부
     pub auto_focus_settings: FocusSettings, // Auto Focus 기능 Settings
     pub focus_border_enabled: bool,         // 포커싱 테두리 기능 사용 여부
+    pub dock_settings: DockSettings,        // 화면 가장자리 도킹 설정
+    pub anchor_enabled: bool,               // 게임 창에 붙여서 따라가기 여부
     pub default_tab: String,                // 실행 시 기본적으로 보일 탭
     pub close_button_behavior: String,      // 닫기 버튼의 행동 설정
     pub auto_detect_title: bool,            // 실행 시 프로그램 이름 자동 감지
+    pub update_channel: UpdateChannel,      // 업데이트 채널 (stable, beta)
+    pub update_mirror_url: Option<String>,  // 업데이트 미러 서버 주소
 }
 
 impl Default for UserSettings {
     fn default() -> Self {
         Self {
             update_check_enabled: true,
+            update_check_interval: 360,
             theme: "light".to_string(),
             class_image: true,
             folded_opacity_enabled: true,
@@ -48,9 +72,31 @@ impl Default for UserSettings {
             auto_focus_enabled: true,
             auto_focus_settings: FocusSettings::default(),
             focus_border_enabled: true,
+            dock_settings: DockSettings::default(),
+            anchor_enabled: false,
             default_tab: "Tab1".to_string(),
             close_button_behavior: "tray".to_string(),
             auto_detect_title: true,
+            update_channel: UpdateChannel::default(),
+            update_mirror_url: None,
+        }
+    }
+}
+
+/// * 업데이트 채널
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    #[default]
+    Stable, // 정식 릴리즈
+    Beta, // 사전 릴리즈
+}
+
+impl UpdateChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => "stable",
+            UpdateChannel::Beta => "beta",
         }
     }
 }
@@ -72,19 +118,127 @@ impl Default for FoldedSettings {
     }
 }
 
+/// * Window Docking 설정 구조체
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct DockSettings {
+    pub snap_distance: u32,   // 도킹되는 가장자리 거리 (논리 px)
+    pub undock_distance: u32, // 도킹 해제까지 끌어야 하는 거리 (논리 px)
+    pub edges: DockEdges,     // 가장자리별 도킹 허용 여부
+    pub auto_hide: bool,      // 도킹 후 대기시간이 지나면 가장자리 밖으로 숨김
+    pub hide_delay: u64,      // 마우스가 창 밖으로 나간 뒤 숨기기까지 대기시간 (sec)
+    pub reveal_strip: u32,    // 숨겼을 때 화면에 남는 두께 (논리 px)
+}
+
+impl Default for DockSettings {
+    fn default() -> Self {
+        Self {
+            snap_distance: 5,
+            undock_distance: 20,
+            edges: DockEdges::default(),
+            auto_hide: false,
+            hide_delay: 3,
+            reveal_strip: 4,
+        }
+    }
+}
+
+/// * 가장자리별 도킹 허용 여부
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct DockEdges {
+    pub left: bool,
+    pub right: bool,
+    pub top: bool,
+    pub bottom: bool,
+}
+
+impl Default for DockEdges {
+    fn default() -> Self {
+        Self {
+            left: true,
+            right: true,
+            top: true,
+            bottom: true,
+        }
+    }
+}
+
+/// * 도킹된 가장자리 (모서리는 두 가장자리에 함께 도킹된 상태)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DockPosition {
+    #[default]
+    Floating,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// * Window Docking 상태 구조체
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct DockState {
+    pub position: DockPosition,     // 도킹된 가장자리
+    pub monitor_id: Option<String>, // 도킹된 모니터 (자유 이동 상태면 None)
+}
+
+/// * 게임 창 내용 영역의 모서리
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnchorCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// * 게임 창 기준 위치 구조체
+// ? 오버레이의 같은 모서리가 게임 창 모서리에서 떨어진 거리 (물리 px)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowAnchor {
+    pub corner: AnchorCorner,
+    pub offset_x: i32,
+    pub offset_y: i32,
+}
+
+/// ? 기본 게임 창 이름 (시작 부분 비교, "LOST ARK (64-bit, DX11) v.3.5.7.1")
+// ? "LOST ARK"만 비교하면 "LOST ARK 공략 - Chrome" 같은 브라우저 창도 일치하므로 여는 괄호까지 포함
+pub const DEFAULT_GAME_TITLE: &str = "LOST ARK (";
+
 /// * Window Focus 설정 구조체
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(default)]
 pub struct FocusSettings {
-    pub game_title: String,   // 창 이름
-    pub shift_idle_time: u64, // sec
+    pub game_title: String,         // 창 이름 (또는 정규식, 실행 파일 이름)
+    pub match_mode: TitleMatchMode, // 창 이름 비교 방식
+    pub shift_idle_time: u64,       // sec
 }
 
 impl Default for FocusSettings {
     fn default() -> Self {
         Self {
-            game_title: "LOST ARK (64-bit, DX11) v.3.5.7.1".to_string(),
+            game_title: DEFAULT_GAME_TITLE.to_string(),
+            match_mode: TitleMatchMode::default(),
             shift_idle_time: 1,
         }
     }
 }
+
+/// * 게임 창 이름 비교 방식
+// ? 클라이언트 버전이 창 제목에 포함되어 있어서 기본값은 접두사 비교
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TitleMatchMode {
+    Exact, // 제목 전체 일치
+    #[default]
+    Prefix, // 제목 시작 부분 일치
+    Contains, // 제목에 포함
+    Regex, // 정규식
+    Process, // 실행 파일 이름 (예: LOSTARK.exe)
+}
@@ -1,3 +1,4 @@
+use crate::window_utils::auto_focus_shift::{resume_mouse_tracking, suspend_mouse_tracking};
 use crate::WINDOW_LABEL;
 use tauri::{
     menu::MenuEvent,
@@ -33,12 +34,14 @@ fn show_main_window(app: &AppHandle) {
         let _ = window.unminimize();
         let _ = window.set_focus();
         let _ = window.set_ignore_cursor_events(false);
+        resume_mouse_tracking();
     }
 }
 
 fn hide_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window(WINDOW_LABEL) {
         let _ = window.hide();
+        suspend_mouse_tracking(); // ? 숨겨진 동안 포커스 추적 중지
     }
 }
 
@@ -50,5 +53,6 @@ fn restore_main_window_size(app: &AppHandle) {
         let _ = window.set_ignore_cursor_events(false);
         let _ = window.set_size(LogicalSize::new(320.0, 500.0));
         let _ = window.set_position(LogicalPosition::new(0.0, 0.0));
+        resume_mouse_tracking();
     }
 }
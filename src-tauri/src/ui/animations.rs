@@ -4,7 +4,7 @@
  * ! 재사용 하기 위해 만들어진 모듈입니다.
  */
 use std::sync::Arc;
-use tauri::{PhysicalSize, Window};
+use tauri::{PhysicalPosition, PhysicalSize, WebviewWindow, Window};
 use tokio::time::{interval, Duration};
 
 #[allow(unused)]
@@ -158,3 +158,44 @@ pub async fn animate_window_resize(
     }
     println!("3: animate_window_resize End!");
 }
+
+/// + 창 이동 애니메이션 함수 (도킹 자동 숨기기 슬라이드)
+pub async fn animate_window_move(
+    window: &WebviewWindow,
+    target: PhysicalPosition<i32>,
+    duration_ms: u64,
+    easing_function: fn(f64) -> f64,
+) {
+    let Ok(current_position) = window.outer_position() else {
+        return;
+    };
+    let current_x = current_position.x as f64;
+    let current_y = current_position.y as f64;
+
+    let steps = 60;
+    let step_duration = Duration::from_millis(duration_ms / steps);
+
+    let x_change = target.x as f64 - current_x;
+    let y_change = target.y as f64 - current_y;
+
+    let mut interval = interval(step_duration);
+
+    for i in 0..=steps {
+        interval.tick().await;
+        let t = i as f64 / steps as f64;
+        let easing_value = easing_function(t);
+
+        let new_x = current_x + x_change * easing_value;
+        let new_y = current_y + y_change * easing_value;
+
+        if window
+            .set_position(PhysicalPosition::new(
+                new_x.round() as i32,
+                new_y.round() as i32,
+            ))
+            .is_err()
+        {
+            break;
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use super::endpoint::changelog_url;
+use super::rollback::updated_from_version;
+use super::signature::verify_remote_signature;
+use crate::state::persist::mark_dirty;
+use crate::state::store::{get_state, loaded_existing_state};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+// > 릴리즈 노트 (업데이트 후 변경 사항)
+// ? changelog.json에는 모든 버전의 릴리즈 노트가 들어있음
+// ? 마지막으로 확인한 버전(AppState.last_seen_version)부터 현재 버전까지의 노트를 보여줌
+// ? last_seen_version이 없으면 update_pending.json의 이전 버전을 사용
+// ? 그것도 없으면 기존 상태 파일이 있을 때만 (이전 버전에서 업데이트) 현재 버전 노트 표시
+
+/// * 릴리즈 노트 구조체
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ChangelogEntry {
+    pub version: String,
+    pub pub_date: String,
+    pub notes: String,
+}
+
+/// * changelog.json 구조체
+#[derive(Debug, Deserialize, Clone)]
+pub struct Changelog {
+    pub releases: Vec<ChangelogEntry>,
+}
+
+/// * 업데이트 후 변경 사항 구조체
+#[derive(Debug, Serialize, Clone)]
+pub struct WhatsNew {
+    pub previous_version: String,
+    pub current_version: String,
+    pub releases: Vec<ChangelogEntry>, // 최신 버전부터 정렬
+}
+
+impl Changelog {
+    /// + `from` 초과 `to` 이하 버전의 노트 (최신 버전부터)
+    // ? 버전 형식이 잘못된 항목은 무시
+    pub fn between(&self, from: &Version, to: &Version) -> Vec<ChangelogEntry> {
+        let mut releases: Vec<(Version, ChangelogEntry)> = self
+            .releases
+            .iter()
+            .filter_map(|entry| Some((Version::parse(&entry.version).ok()?, entry.clone())))
+            .filter(|(version, _)| version > from && version <= to)
+            .collect();
+
+        releases.sort_by(|a, b| b.0.cmp(&a.0));
+        releases.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// + `version` 바로 이전 릴리즈 버전 (없으면 0.0.0)
+    pub fn release_before(&self, version: &Version) -> Version {
+        self.releases
+            .iter()
+            .filter_map(|entry| Version::parse(&entry.version).ok())
+            .filter(|v| v < version)
+            .max()
+            .unwrap_or_else(|| Version::new(0, 0, 0))
+    }
+}
+
+/// + changelog.json 가져오기 (서명 검증 포함)
+pub async fn fetch_changelog(url: &str) -> Result<Changelog, String> {
+    let bytes = reqwest::get(url)
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("릴리즈 노트 요청 실패: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| format!("바이트 변환 실패: {}", e))?;
+
+    verify_remote_signature("changelog.json", url, &bytes).await?;
+
+    serde_json::from_slice(&bytes).map_err(|e| format!("릴리즈 노트 파싱 실패: {}", e))
+}
+
+// > 릴리즈 노트 (업데이트 후 변경 사항)
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+/// ? 마지막으로 확인한 버전 이후 변경 사항 (업데이트되지 않았으면 None)
+// ? 확인 처리는 Frontend에서 노트를 보여준 뒤 mark_whats_new_seen으로 따로 요청
+#[tauri::command]
+pub async fn get_whats_new(app: AppHandle) -> Result<Option<WhatsNew>, String> {
+    let current_version = app.package_info().version.to_string();
+    let current =
+        Version::parse(&current_version).map_err(|e| format!("현재 버전 파싱 실패: {}", e))?;
+
+    let (last_seen, url) = {
+        let mut app_state = get_state().lock().await;
+        let last_seen = app_state
+            .last_seen_version
+            .clone()
+            .or_else(|| updated_from_version().map(str::to_string));
+
+        // ? 처음 설치했다면 보여줄 노트 없이 현재 버전만 기록
+        if last_seen.is_none() && !loaded_existing_state() {
+            app_state.last_seen_version = Some(current_version);
+            mark_dirty();
+            return Ok(None);
+        }
+        (last_seen, changelog_url(&app_state.user_settings))
+    };
+
+    let previous = match &last_seen {
+        Some(version) => {
+            let previous =
+                Version::parse(version).map_err(|e| format!("이전 버전 파싱 실패: {}", e))?;
+            if previous >= current {
+                return Ok(None);
+            }
+            Some(previous)
+        }
+        None => None,
+    };
+
+    let changelog = fetch_changelog(&url).await?;
+    // ? 이전 버전을 알 수 없으면 (릴리즈 노트 기능 이전 버전에서 업데이트) 현재 버전 노트만 표시
+    let previous = previous.unwrap_or_else(|| changelog.release_before(&current));
+    Ok(Some(WhatsNew {
+        previous_version: previous.to_string(),
+        current_version,
+        releases: changelog.between(&previous, &current),
+    }))
+}
+
+/// ? 현재 버전의 변경 사항을 확인했다고 기록
+#[tauri::command]
+pub async fn mark_whats_new_seen(app: AppHandle) -> Result<(), String> {
+    let mut app_state = get_state().lock().await;
+    app_state.last_seen_version = Some(app.package_info().version.to_string());
+    mark_dirty();
+    Ok(())
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changelog(versions: &[&str]) -> Changelog {
+        Changelog {
+            releases: versions
+                .iter()
+                .map(|version| ChangelogEntry {
+                    version: version.to_string(),
+                    pub_date: String::new(),
+                    notes: format!("v{}", version),
+                })
+                .collect(),
+        }
+    }
+
+    fn version(text: &str) -> Version {
+        Version::parse(text).unwrap()
+    }
+
+    #[test]
+    fn between_returns_newest_first() {
+        let changelog = changelog(&["1.2.3", "1.2.5", "1.2.4", "1.3.0", "잘못된 버전"]);
+
+        let releases = changelog.between(&version("1.2.3"), &version("1.2.5"));
+        let versions: Vec<_> = releases.iter().map(|r| r.version.as_str()).collect();
+        assert_eq!(versions, vec!["1.2.5", "1.2.4"]);
+    }
+
+    #[test]
+    fn release_before_shows_only_current_notes() {
+        let changelog = changelog(&["1.2.3", "1.2.4", "1.2.5"]);
+
+        let previous = changelog.release_before(&version("1.2.5"));
+        assert_eq!(previous, version("1.2.4"));
+        assert_eq!(changelog.between(&previous, &version("1.2.5")).len(), 1);
+
+        // ? 이전 릴리즈가 없으면 0.0.0
+        assert_eq!(
+            changelog.release_before(&version("1.2.3")),
+            Version::new(0, 0, 0)
+        );
+    }
+}
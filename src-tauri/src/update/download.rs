@@ -0,0 +1,421 @@
+use super::signature::{invalid_signature, parse_signature, update_public_key};
+use reqwest::{header::RANGE, Client, StatusCode};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+use tauri::{AppHandle, Emitter};
+use tokio::{
+    fs::File,
+    io::{AsyncSeekExt, AsyncWriteExt},
+};
+
+// > 업데이트 파일 다운로드
+// ? 받은 조각을 바로 파일에 쓰면서 SHA256/서명 해시를 함께 계산
+// ? 연결이 끊기면 받은 위치부터 Range 요청으로 이어받기
+// ? 4xx 응답은 재시도하지 않음 (416은 이어받을 위치가 맞지 않으므로 처음부터 다시 받기)
+
+pub const PROGRESS_EVENT: &str = "update://progress";
+
+/// ? 연결이 끊겼을 때 이어받기 최대 시도 횟수
+const MAX_RETRIES: u32 = 5;
+/// ? 진행률 이벤트 최소 간격
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// * 다운로드 진행률 구조체
+#[derive(Serialize, Clone, Debug)]
+pub struct DownloadProgress {
+    pub file: String,       // 다운로드 대상 이름
+    pub downloaded: u64,    // 받은 바이트
+    pub total: Option<u64>, // 전체 바이트 (서버가 알려주지 않으면 None)
+    pub speed: u64,         // 평균 속도 (bytes/sec)
+}
+
+/// + 파일 다운로드 (해시, 서명 검증 포함, 진행률은 PROGRESS_EVENT로 전송)
+pub async fn download_file(
+    app: &AppHandle,
+    file: &str,
+    url: &str,
+    dest: &Path,
+    expected_sha256: &str,
+    signature: &str,
+) -> Result<(), String> {
+    download_with_progress(file, url, dest, expected_sha256, signature, |progress| {
+        let _ = app.emit(PROGRESS_EVENT, progress);
+    })
+    .await
+}
+
+/// + 파일 다운로드 (진행률은 `on_progress`로 전달)
+// ? 검증에 실패하면 받은 파일을 삭제하고 오류 반환
+pub async fn download_with_progress(
+    file: &str,
+    url: &str,
+    dest: &Path,
+    expected_sha256: &str,
+    signature: &str,
+    mut on_progress: impl FnMut(DownloadProgress),
+) -> Result<(), String> {
+    let signature = parse_signature(file, signature)?;
+    let mut verifier = update_public_key()
+        .verify_stream(&signature)
+        .map_err(|e| invalid_signature(file, e))?;
+    let mut hasher = Sha256::new();
+
+    let client = Client::new();
+    let mut output = File::create(dest)
+        .await
+        .map_err(|e| format!("{} 파일 생성 실패: {}", file, e))?;
+
+    let started_at = Instant::now();
+    let mut last_emit = started_at;
+    let mut downloaded: u64 = 0;
+    let mut total: Option<u64> = None;
+    let mut retries = 0;
+    let mut rewind = false; // ? 416 응답 후 처음부터 다시 받기
+
+    loop {
+        let mut request = client.get(url);
+        if downloaded > 0 {
+            request = request.header(RANGE, format!("bytes={}-", downloaded));
+        }
+
+        let result = async {
+            let response = request.send().await?;
+            let status = response.status();
+            if status == StatusCode::RANGE_NOT_SATISFIABLE && downloaded > 0 {
+                return Err(DownloadError::RangeNotSatisfiable);
+            }
+            if status.is_client_error() {
+                return Err(DownloadError::Status(status));
+            }
+            let mut response = response.error_for_status()?;
+
+            if rewind || (downloaded > 0 && status != StatusCode::PARTIAL_CONTENT) {
+                // ? 서버가 Range를 지원하지 않으면 처음부터 다시 받기
+                if !rewind {
+                    println!(
+                        "⚠️ 이어받기를 지원하지 않아 처음부터 다시 받습니다. ({})",
+                        file
+                    );
+                }
+                rewind = false;
+                downloaded = 0;
+                hasher = Sha256::new();
+                verifier = update_public_key()
+                    .verify_stream(&signature)
+                    .expect("이미 확인된 서명");
+                output.set_len(0).await.map_err(DownloadError::Io)?;
+                output
+                    .seek(std::io::SeekFrom::Start(0))
+                    .await
+                    .map_err(DownloadError::Io)?;
+            }
+            if total.is_none() {
+                total = response.content_length().map(|len| len + downloaded);
+            }
+
+            while let Some(chunk) = response.chunk().await? {
+                output.write_all(&chunk).await.map_err(DownloadError::Io)?;
+                hasher.update(&chunk);
+                verifier.update(&chunk);
+                downloaded += chunk.len() as u64;
+
+                if last_emit.elapsed() >= PROGRESS_INTERVAL {
+                    last_emit = Instant::now();
+                    on_progress(progress(file, downloaded, total, started_at));
+                }
+            }
+            Ok::<(), DownloadError>(())
+        }
+        .await;
+
+        match result {
+            Ok(()) => break,
+            Err(DownloadError::Io(e)) => {
+                let _ = tokio::fs::remove_file(dest).await;
+                return Err(format!("{} 파일 저장 실패: {}", file, e));
+            }
+            Err(DownloadError::Status(status)) => {
+                let _ = tokio::fs::remove_file(dest).await;
+                return Err(format!("{} 다운로드 실패: {}", file, status));
+            }
+            Err(DownloadError::RangeNotSatisfiable) if retries < MAX_RETRIES => {
+                retries += 1;
+                println!(
+                    "⚠️ {} 이어받기 위치가 맞지 않아 처음부터 다시 받습니다. {}/{}",
+                    file, retries, MAX_RETRIES
+                );
+                downloaded = 0;
+                total = None;
+                rewind = true;
+            }
+            Err(DownloadError::RangeNotSatisfiable) => {
+                let _ = tokio::fs::remove_file(dest).await;
+                return Err(format!(
+                    "{} 다운로드 실패: {}",
+                    file,
+                    StatusCode::RANGE_NOT_SATISFIABLE
+                ));
+            }
+            Err(DownloadError::Http(e)) if retries < MAX_RETRIES => {
+                retries += 1;
+                println!(
+                    "⚠️ {} 다운로드 중단 ({}), 이어받기 재시도 {}/{}",
+                    file, e, retries, MAX_RETRIES
+                );
+                tokio::time::sleep(Duration::from_secs(retries as u64)).await;
+            }
+            Err(DownloadError::Http(e)) => {
+                let _ = tokio::fs::remove_file(dest).await;
+                return Err(format!("{} 다운로드 실패: {}", file, e));
+            }
+        }
+    }
+
+    output
+        .flush()
+        .await
+        .map_err(|e| format!("{} 파일 저장 실패: {}", file, e))?;
+    drop(output);
+    on_progress(progress(
+        file,
+        downloaded,
+        total.or(Some(downloaded)),
+        started_at,
+    ));
+
+    // ? 해시 검증
+    let actual_hash = format!("{:x}", hasher.finalize());
+    if actual_hash != expected_sha256.to_lowercase() {
+        let _ = tokio::fs::remove_file(dest).await;
+        return Err(format!(
+            "❌ {} 해시 불일치\n예상: {}\n실제: {}",
+            file, expected_sha256, actual_hash
+        ));
+    }
+
+    // ? 서명 검증
+    if let Err(e) = verifier.finalize() {
+        let _ = tokio::fs::remove_file(dest).await;
+        return Err(invalid_signature(file, e));
+    }
+
+    Ok(())
+}
+
+/// * 다운로드 오류 (네트워크, 5xx 오류만 이어받기 재시도)
+enum DownloadError {
+    Http(reqwest::Error),
+    Status(StatusCode),  // 4xx 응답 (재시도하지 않음)
+    RangeNotSatisfiable, // 416 응답 (처음부터 다시 받기)
+    Io(std::io::Error),
+}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(e: reqwest::Error) -> Self {
+        DownloadError::Http(e)
+    }
+}
+
+/// + 진행률 계산
+fn progress(
+    file: &str,
+    downloaded: u64,
+    total: Option<u64>,
+    started_at: Instant,
+) -> DownloadProgress {
+    let elapsed = started_at.elapsed().as_secs_f64();
+    let speed = if elapsed > 0.0 {
+        (downloaded as f64 / elapsed) as u64
+    } else {
+        0
+    };
+
+    DownloadProgress {
+        file: file.to_string(),
+        downloaded,
+        total,
+        speed,
+    }
+}
+
+// > 업데이트 파일 다운로드
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    // ? 테스트 전용 키(keys/test.pub)로 서명된 파일을 다운로드 대상으로 사용
+    const PAYLOAD: &[u8] = include_bytes!("../../tests/fixtures/raids/raids.json");
+    const PAYLOAD_SIG: &str = include_str!("../../tests/fixtures/raids/raids.json.minisig");
+
+    /// * 테스트 서버 응답 (Content-Length보다 짧은 본문을 보내면 연결이 중간에 끊긴 것처럼 동작)
+    struct Reply {
+        status: u16,
+        length: usize,
+        body: Vec<u8>,
+    }
+
+    fn reply(status: u16, body: &[u8]) -> Reply {
+        Reply {
+            status,
+            length: body.len(),
+            body: body.to_vec(),
+        }
+    }
+
+    /// + 앞부분만 보내고 연결을 끊는 응답
+    fn cut_off(at: usize) -> Reply {
+        Reply {
+            status: 200,
+            length: PAYLOAD.len(),
+            body: PAYLOAD[..at].to_vec(),
+        }
+    }
+
+    /// + 요청마다 순서대로 응답하는 로컬 HTTP 서버 시작 (주소, 요청별 Range 헤더 반환)
+    async fn serve(replies: Vec<Reply>) -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&ranges);
+
+        tokio::spawn(async move {
+            for reply in replies {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let range = request
+                    .lines()
+                    .find_map(|line| line.strip_prefix("range:"))
+                    .map(|value| value.trim().to_string());
+                recorded.lock().unwrap().push(range);
+
+                let head = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    reply.status, reply.length
+                );
+                let _ = stream.write_all(head.as_bytes()).await;
+                let _ = stream.write_all(&reply.body).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+
+        (format!("http://{}/release.exe", addr), ranges)
+    }
+
+    fn payload_sha256() -> String {
+        format!("{:x}", Sha256::digest(PAYLOAD))
+    }
+
+    /// + 임시 폴더에 다운로드하고 (결과, 받은 파일, 진행률 목록) 반환
+    async fn download(
+        url: &str,
+        sha256: &str,
+    ) -> (Result<(), String>, Option<Vec<u8>>, Vec<DownloadProgress>) {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("release.exe");
+        let mut events = Vec::new();
+
+        let result =
+            download_with_progress("release", url, &dest, sha256, PAYLOAD_SIG, |progress| {
+                events.push(progress)
+            })
+            .await;
+
+        (result, std::fs::read(&dest).ok(), events)
+    }
+
+    #[tokio::test]
+    async fn resumes_with_range_request() {
+        let half = PAYLOAD.len() / 2;
+        let (url, ranges) = serve(vec![cut_off(half), reply(206, &PAYLOAD[half..])]).await;
+
+        let (result, saved, _) = download(&url, &payload_sha256()).await;
+        result.unwrap();
+        assert_eq!(saved.as_deref(), Some(PAYLOAD));
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            vec![None, Some(format!("bytes={}-", half))]
+        );
+    }
+
+    #[tokio::test]
+    async fn restarts_when_range_is_ignored() {
+        let (url, ranges) = serve(vec![cut_off(10), reply(200, PAYLOAD)]).await;
+
+        let (result, saved, _) = download(&url, &payload_sha256()).await;
+        result.unwrap();
+        assert_eq!(saved.as_deref(), Some(PAYLOAD)); // ? 앞부분이 중복으로 쓰이지 않음
+        assert_eq!(ranges.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn restarts_without_range_on_416() {
+        let (url, ranges) = serve(vec![cut_off(10), reply(416, b""), reply(200, PAYLOAD)]).await;
+
+        let (result, saved, _) = download(&url, &payload_sha256()).await;
+        result.unwrap();
+        assert_eq!(saved.as_deref(), Some(PAYLOAD));
+        assert_eq!(
+            *ranges.lock().unwrap(),
+            vec![None, Some("bytes=10-".to_string()), None]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let (url, ranges) = serve(vec![reply(404, b""), reply(200, PAYLOAD)]).await;
+
+        let (result, saved, _) = download(&url, &payload_sha256()).await;
+        assert!(result.unwrap_err().contains("404"));
+        assert_eq!(saved, None);
+        assert_eq!(ranges.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn hash_mismatch_removes_file() {
+        let (url, _) = serve(vec![reply(200, PAYLOAD)]).await;
+
+        let (result, saved, _) = download(&url, &"0".repeat(64)).await;
+        assert!(result.unwrap_err().contains("해시 불일치"));
+        assert_eq!(saved, None);
+    }
+
+    #[tokio::test]
+    async fn tampered_file_fails_signature() {
+        let mut tampered = PAYLOAD.to_vec();
+        tampered[0] ^= 1;
+        let (url, _) = serve(vec![reply(200, &tampered)]).await;
+
+        let sha256 = format!("{:x}", Sha256::digest(&tampered));
+        let (result, saved, _) = download(&url, &sha256).await;
+        assert!(result.unwrap_err().contains("signature invalid"));
+        assert_eq!(saved, None);
+    }
+
+    #[tokio::test]
+    async fn reports_final_progress() {
+        let (url, _) = serve(vec![reply(200, PAYLOAD)]).await;
+
+        let (result, _, events) = download(&url, &payload_sha256()).await;
+        result.unwrap();
+
+        let last = events.last().unwrap();
+        assert_eq!(last.file, "release");
+        assert_eq!(last.downloaded, PAYLOAD.len() as u64);
+        assert_eq!(last.total, Some(PAYLOAD.len() as u64));
+        assert!(events
+            .windows(2)
+            .all(|w| w[0].downloaded <= w[1].downloaded));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::state::types::{UpdateChannel, UserSettings};
+use std::env;
+
+// > 업데이트 서버 주소
+// ? 우선순위: LOAASSIST_UPDATE_URL 환경 변수 > 사용자 미러 서버 > GitHub Releases
+// ? 미러 서버는 {mirror}/{channel}/latest.json 구조를 사용
+// ? raids.json만 따로 바꿀 때는 LOAASSIST_RAIDS_URL 환경 변수 (매니페스트 전체 주소)
+
+const RELEASES_URL: &str = "https://github.com/CasinGit/LoaAssist/releases";
+
+/// + 채널별 GitHub Releases 다운로드 주소
+fn github_base_url(channel: UpdateChannel) -> String {
+    match channel {
+        UpdateChannel::Stable => format!("{}/latest/download", RELEASES_URL),
+        UpdateChannel::Beta => format!("{}/download/beta", RELEASES_URL),
+    }
+}
+
+/// + 업데이트 파일들이 위치한 기본 주소 (끝에 `/` 없음)
+pub fn update_base_url(settings: &UserSettings) -> String {
+    if let Ok(url) = env::var("LOAASSIST_UPDATE_URL") {
+        if !url.trim().is_empty() {
+            return url.trim().trim_end_matches('/').to_string();
+        }
+    }
+
+    match settings.update_mirror_url.as_deref().map(str::trim) {
+        Some(mirror) if !mirror.is_empty() => format!(
+            "{}/{}",
+            mirror.trim_end_matches('/'),
+            settings.update_channel.as_str()
+        ),
+        _ => github_base_url(settings.update_channel),
+    }
+}
+
+/// + latest.json 주소
+pub fn latest_manifest_url(settings: &UserSettings) -> String {
+    format!("{}/latest.json", update_base_url(settings))
+}
+
+/// + raids.json 주소
+pub fn raids_manifest_url(settings: &UserSettings) -> String {
+    if let Ok(url) = env::var("LOAASSIST_RAIDS_URL") {
+        if !url.trim().is_empty() {
+            return url.trim().to_string();
+        }
+    }
+    format!("{}/raids.json", update_base_url(settings))
+}
+
+/// + changelog.json 주소
+pub fn changelog_url(settings: &UserSettings) -> String {
+    format!("{}/changelog.json", update_base_url(settings))
+}
+
+// > 업데이트 서버 주소
@@ -1,3 +1,10 @@
+pub mod changelog;
+pub mod download;
+pub mod endpoint;
+pub mod raids;
+pub mod rollback;
+pub mod scheduler;
+pub mod signature;
 pub mod update;
 
 pub use update::get_update_check_result;
@@ -0,0 +1,256 @@
+use super::endpoint::raids_manifest_url;
+use super::signature::verify_remote_signature;
+use crate::state::catalogue::{record_catalogue_sync, CatalogueDiff, RaidCatalogue};
+use crate::state::store::get_state;
+use reqwest::Url;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter};
+
+// > 원격 레이드 카탈로그 업데이트
+// ? 프로그램 업데이트 없이 raids.json 매니페스트로 기본 레이드 카탈로그만 갱신
+// ! 원격 카탈로그와 프로그램에 포함된 카탈로그(data/default_raids.json)는 같은 버전 번호를 공유
+// ! (npm run raids:check 로 배포 전에 확인, 원격 v5 배포 후 포함 카탈로그를 v4로 내보내면 적용되지 않음)
+
+pub const CATALOGUE_UPDATED_EVENT: &str = "catalogue://updated";
+
+/// * raids.json 매니페스트 구조체
+#[derive(Debug, Deserialize, Clone)]
+pub struct RaidsManifest {
+    pub version: u32,
+    pub url: String, // 카탈로그 파일 주소 (매니페스트 기준 상대 경로 가능)
+    pub sha256: String,
+}
+
+/// + 바이트 SHA256 해시 계산
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// + 매니페스트와 카탈로그를 받아서 검증
+// ? 매니페스트 버전이 `current_version` 이하이면 카탈로그를 받지 않고 None
+pub async fn fetch_remote_catalogue(
+    manifest_url: &str,
+    current_version: u32,
+) -> Result<Option<RaidCatalogue>, String> {
+    let manifest_url =
+        Url::parse(manifest_url).map_err(|e| format!("매니페스트 주소 파싱 실패: {}", e))?;
+
+    // * 1. raids.json 매니페스트 가져오기
+    let response = reqwest::get(manifest_url.clone())
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("매니페스트 요청 실패: {}", e))?;
+    let manifest_bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("바이트 변환 실패: {}", e))?;
+
+    // ? 파싱 전에 raids.json.minisig로 서명 검증 (해시를 매니페스트가 보장하므로)
+    verify_remote_signature("raids.json", manifest_url.as_str(), &manifest_bytes).await?;
+
+    let manifest: RaidsManifest = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| format!("매니페스트 파싱 실패: {}", e))?;
+    if manifest.version <= current_version {
+        return Ok(None);
+    }
+
+    // * 2. 카탈로그 파일 다운로드
+    let catalogue_url = manifest_url
+        .join(&manifest.url)
+        .map_err(|e| format!("카탈로그 주소 파싱 실패: {}", e))?;
+    let bytes = reqwest::get(catalogue_url)
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("카탈로그 다운로드 실패: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| format!("바이트 변환 실패: {}", e))?;
+
+    // * 3. 해시 검증
+    let actual_hash = sha256_hex(&bytes);
+    if actual_hash != manifest.sha256.to_lowercase() {
+        return Err(format!(
+            "❌ 카탈로그 해시 불일치\n예상: {}\n실제: {}",
+            manifest.sha256, actual_hash
+        ));
+    }
+
+    // * 4. 타입 검증
+    let catalogue: RaidCatalogue =
+        serde_json::from_slice(&bytes).map_err(|e| format!("카탈로그 파싱 실패: {}", e))?;
+    if catalogue.version != manifest.version {
+        return Err(format!(
+            "카탈로그 버전 불일치 (매니페스트: {}, 카탈로그: {})",
+            manifest.version, catalogue.version
+        ));
+    }
+    catalogue.validate()?;
+
+    Ok(Some(catalogue))
+}
+
+/// + 원격 카탈로그 확인 후 더 높은 버전이면 적용
+pub async fn update_remote_catalogue(
+    app: &AppHandle,
+    manifest_url: &str,
+) -> Result<Option<CatalogueDiff>, String> {
+    let current_version = get_state().lock().await.homework.default_raids_version;
+    let Some(catalogue) = fetch_remote_catalogue(manifest_url, current_version).await? else {
+        println!(
+            "✅ 레이드 카탈로그가 최신 버전입니다 (v{})",
+            current_version
+        );
+        return Ok(None);
+    };
+
+    let mut app_state = get_state().lock().await;
+    let diff = app_state.homework.sync_default_raids(&catalogue);
+    drop(app_state); // ? 상태 락 해제
+
+    if let Some(diff) = &diff {
+        record_catalogue_sync(diff);
+        let _ = app.emit(CATALOGUE_UPDATED_EVENT, diff.clone());
+    }
+    Ok(diff)
+}
+
+// > 원격 레이드 카탈로그 업데이트
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+#[tauri::command]
+pub async fn check_raid_catalogue_update(app: AppHandle) -> Result<Option<CatalogueDiff>, String> {
+    let manifest_url = raids_manifest_url(&get_state().lock().await.user_settings);
+    update_remote_catalogue(&app, &manifest_url).await
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    // ? tests/fixtures의 파일들은 테스트 전용 키(keys/test.pub)로 서명됨
+    const MANIFEST: &[u8] = include_bytes!("../../tests/fixtures/raids/raids.json");
+    const MANIFEST_SIG: &[u8] = include_bytes!("../../tests/fixtures/raids/raids.json.minisig");
+    const CATALOGUE: &[u8] = include_bytes!("../../tests/fixtures/raids/catalogue.json");
+    const MALFORMED: &[u8] = include_bytes!("../../tests/fixtures/raids/malformed/raids.json");
+    const MALFORMED_SIG: &[u8] =
+        include_bytes!("../../tests/fixtures/raids/malformed/raids.json.minisig");
+
+    /// + 경로별 (상태 코드, 본문)을 응답하는 로컬 HTTP 서버 시작 후 주소 반환
+    async fn serve(routes: Vec<(&'static str, u16, Vec<u8>)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let routes: HashMap<_, _> = routes
+            .into_iter()
+            .map(|(path, status, body)| (path, (status, body)))
+            .collect();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+                let (status, body) = routes.get(path).cloned().unwrap_or((404, Vec::new()));
+                let head = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                let _ = stream.write_all(head.as_bytes()).await;
+                let _ = stream.write_all(&body).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    /// + 정상 매니페스트, 서명, 카탈로그 경로
+    fn valid_routes() -> Vec<(&'static str, u16, Vec<u8>)> {
+        vec![
+            ("/raids.json", 200, MANIFEST.to_vec()),
+            ("/raids.json.minisig", 200, MANIFEST_SIG.to_vec()),
+            ("/catalogue.json", 200, CATALOGUE.to_vec()),
+        ]
+    }
+
+    #[tokio::test]
+    async fn downloads_newer_catalogue() {
+        let base = serve(valid_routes()).await;
+
+        let catalogue = fetch_remote_catalogue(&format!("{}/raids.json", base), 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(catalogue.version, 2);
+        assert_eq!(catalogue.raids.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn same_version_is_unchanged() {
+        let base = serve(valid_routes()).await;
+
+        let result = fetch_remote_catalogue(&format!("{}/raids.json", base), 2).await;
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn malformed_manifest_is_rejected() {
+        let base = serve(vec![
+            ("/raids.json", 200, MALFORMED.to_vec()),
+            ("/raids.json.minisig", 200, MALFORMED_SIG.to_vec()),
+        ])
+        .await;
+
+        let error = fetch_remote_catalogue(&format!("{}/raids.json", base), 1)
+            .await
+            .unwrap_err();
+        assert!(error.contains("매니페스트 파싱 실패"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn tampered_manifest_is_rejected() {
+        let mut routes = valid_routes();
+        routes[0].2 = MALFORMED.to_vec(); // ? 다른 파일의 서명
+
+        let base = serve(routes).await;
+        let error = fetch_remote_catalogue(&format!("{}/raids.json", base), 1)
+            .await
+            .unwrap_err();
+        assert!(error.contains("signature invalid"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn server_error_is_reported() {
+        let base = serve(vec![("/raids.json", 500, Vec::new())]).await;
+
+        let error = fetch_remote_catalogue(&format!("{}/raids.json", base), 1)
+            .await
+            .unwrap_err();
+        assert!(error.contains("매니페스트 요청 실패"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn network_error_is_reported() {
+        // ? 포트를 잡았다가 바로 닫아서 연결이 거부되는 주소
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let error = fetch_remote_catalogue(&format!("http://{}/raids.json", addr), 1)
+            .await
+            .unwrap_err();
+        assert!(error.contains("매니페스트 요청 실패"), "{}", error);
+    }
+}
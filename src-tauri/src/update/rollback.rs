@@ -0,0 +1,196 @@
+use crate::state::store::get_state_file_path;
+use serde::{Deserialize, Serialize};
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tauri::utils::platform::current_exe;
+
+// > 업데이트 확인 표시 / 롤백
+// ? bootstrapper 실행 전에 이전 실행 파일을 .bak으로 복사하고 update_pending.json을 남김
+// ? 새 버전이 frontend-ready까지 도달하면 표시와 .bak 파일 삭제
+// ? 새 버전이 frontend-ready 전에 종료되었다면 다음 실행 시 보관한 이전 실행 파일로 복원
+
+const MARKER_FILE: &str = "update_pending.json";
+
+/// ? 이번 실행이 업데이트 직후라면 이전 버전 (릴리즈 노트 표시용)
+static UPDATED_FROM: OnceLock<String> = OnceLock::new();
+
+/// * 업데이트 진행 표시 구조체
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateMarker {
+    pub from_version: String,
+    pub to_version: String,
+    pub original_path: PathBuf, // 업데이트 대상 실행 파일
+    pub backup_path: PathBuf,   // 업데이트 전에 복사해 둔 이전 실행 파일
+    pub started_at: u64,        // unix sec
+    #[serde(default)]
+    pub launch_attempts: u32, // 새 버전 실행 횟수 (frontend-ready 전)
+}
+
+/// + update_pending.json 경로
+fn marker_path() -> Option<PathBuf> {
+    get_state_file_path().and_then(|path| path.parent().map(|dir| dir.join(MARKER_FILE)))
+}
+
+/// + 파일 이름 뒤에 접미사 추가 (LoaAssist.exe → LoaAssist.exe.bak)
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name: OsString = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// + 이전 실행 파일을 보관할 경로
+pub fn backup_exe_path(original_path: &Path) -> PathBuf {
+    with_suffix(original_path, ".bak")
+}
+
+fn read_marker() -> Option<UpdateMarker> {
+    let contents = fs::read_to_string(marker_path()?).ok()?;
+    match serde_json::from_str(&contents) {
+        Ok(marker) => Some(marker),
+        Err(e) => {
+            eprintln!("⚠️ update_pending.json 파싱 실패: {}", e);
+            remove_marker();
+            None
+        }
+    }
+}
+
+fn write_marker(marker: &UpdateMarker) -> Result<(), String> {
+    let path = marker_path().ok_or("AppData 경로를 찾을 수 없습니다.")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("폴더 생성 실패: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(marker).map_err(|e| format!("직렬화 실패: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("update_pending.json 저장 실패: {}", e))
+}
+
+fn remove_marker() {
+    if let Some(path) = marker_path() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// + bootstrapper 실행 전 업데이트 진행 표시 저장
+pub fn write_update_marker(
+    from_version: &str,
+    to_version: &str,
+    original_path: &Path,
+    backup_path: &Path,
+) -> Result<(), String> {
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    write_marker(&UpdateMarker {
+        from_version: from_version.to_string(),
+        to_version: to_version.to_string(),
+        original_path: original_path.to_path_buf(),
+        backup_path: backup_path.to_path_buf(),
+        started_at,
+        launch_attempts: 0,
+    })
+}
+
+/// + 이전 실행 파일로 복원
+// ? 실행 중인 파일은 덮어쓸 수 없지만 이름 변경은 가능하므로 .failed로 옮긴 뒤 복사
+fn restore_previous(marker: &UpdateMarker) -> Result<(), String> {
+    if !marker.backup_path.exists() {
+        return Err(format!(
+            "이전 실행 파일이 없습니다: {:?}",
+            marker.backup_path
+        ));
+    }
+
+    let failed_path = with_suffix(&marker.original_path, ".failed");
+    let _ = fs::remove_file(&failed_path);
+    fs::rename(&marker.original_path, &failed_path)
+        .map_err(|e| format!("실행 파일 이동 실패: {}", e))?;
+
+    if let Err(e) = fs::copy(&marker.backup_path, &marker.original_path) {
+        let _ = fs::rename(&failed_path, &marker.original_path); // ? 원상 복구
+        return Err(format!("이전 실행 파일 복사 실패: {}", e));
+    }
+    Ok(())
+}
+
+/// + 실행 시 업데이트 결과 확인 (init_state 전에 호출)
+// ? 롤백할 때는 상태 파일을 읽지 않으므로 (상위 버전 상태 파일 등) 전역 상태 초기화 전에 실행
+// ? 이전 실행이 frontend-ready에 도달하지 못했다면 롤백 후 이전 버전을 실행하고 종료
+pub fn check_pending_update(current_version: &str) {
+    let Some(mut marker) = read_marker() else {
+        // ? 롤백 후 남은 실패한 실행 파일 정리
+        if let Ok(exe) = current_exe() {
+            let _ = fs::remove_file(with_suffix(&exe, ".failed"));
+        }
+        return;
+    };
+
+    if current_version != marker.to_version {
+        // ? bootstrapper가 업데이트를 적용하지 못한 경우
+        println!(
+            "⚠️ 업데이트가 적용되지 않았습니다 (v{} → v{}, 현재 v{})",
+            marker.from_version, marker.to_version, current_version
+        );
+        remove_marker();
+        return;
+    }
+
+    if marker.launch_attempts == 0 {
+        // ? 새 버전 첫 실행, frontend-ready에서 확인 처리
+        let _ = UPDATED_FROM.set(marker.from_version.clone());
+        marker.launch_attempts += 1;
+        if let Err(e) = write_marker(&marker) {
+            eprintln!("❌ {}", e);
+        }
+        return;
+    }
+
+    // ! 새 버전이 frontend-ready 전에 종료됨 → 이전 버전으로 롤백
+    println!(
+        "🔙 v{} 실행 실패, v{}로 복원합니다.",
+        marker.to_version, marker.from_version
+    );
+    match restore_previous(&marker) {
+        Ok(()) => {
+            remove_marker();
+            if let Err(e) = Command::new(&marker.original_path).spawn() {
+                eprintln!("❌ 이전 버전 실행 실패: {}", e);
+            }
+            // ? 전역 상태 초기화 전이라 저장할 변경 사항 없음
+            std::process::exit(0);
+        }
+        Err(e) => {
+            // ? 복원할 수 없다면 새 버전으로 계속 실행
+            eprintln!("❌ 롤백 실패: {}", e);
+            remove_marker();
+        }
+    }
+}
+
+/// + 업데이트 직후 실행이라면 업데이트 전 버전
+pub fn updated_from_version() -> Option<&'static str> {
+    UPDATED_FROM.get().map(String::as_str)
+}
+
+/// + 새 버전이 정상적으로 실행됨 (frontend-ready 수신 시)
+pub fn confirm_update_health() {
+    let Some(marker) = read_marker() else {
+        return;
+    };
+    if marker.launch_attempts == 0 {
+        return; // ? 아직 bootstrapper로 넘어가기 전
+    }
+
+    println!("✅ 업데이트 확인 완료: v{}", marker.to_version);
+    remove_marker();
+    let _ = fs::remove_file(&marker.backup_path);
+}
+
+// > 업데이트 확인 표시 / 롤백
@@ -0,0 +1,98 @@
+use super::update::{check_for_update, UpdateCheckError, UpdateCheckResult};
+use crate::state::store::get_state;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+// > 백그라운드 업데이트 확인
+// ? 프로그램을 며칠씩 켜두는 사용자도 새 버전을 알 수 있도록 주기적으로 latest.json 확인
+// ? 시작 시 확인은 Frontend(on:app_start_once)에서 하므로 첫 확인은 한 주기 뒤에 실행
+
+pub const UPDATE_AVAILABLE_EVENT: &str = "update://available";
+
+/// ? 사용자가 지정할 수 있는 최소 확인 주기
+const MIN_INTERVAL: Duration = Duration::from_secs(15 * 60);
+/// ? 네트워크 오류(요청 실패) 시 첫 재시도 대기 시간 (실패할 때마다 2배)
+const BACKOFF_BASE: Duration = Duration::from_secs(60);
+/// ? 업데이트 확인이 꺼져있을 때 설정을 다시 확인하는 간격
+const DISABLED_POLL: Duration = Duration::from_secs(60);
+
+/// + 설정된 확인 주기 (min → Duration)
+fn check_interval(minutes: u64) -> Duration {
+    Duration::from_secs(minutes * 60).max(MIN_INTERVAL)
+}
+
+/// + 연속 실패 횟수에 따른 재시도 대기 시간 (확인 주기를 넘지 않음)
+fn backoff_delay(failures: u32, interval: Duration) -> Duration {
+    let factor = 2u32.saturating_pow(failures.saturating_sub(1).min(16));
+    BACKOFF_BASE.saturating_mul(factor).min(interval)
+}
+
+/// + 백그라운드 업데이트 확인 시작
+pub fn start_update_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut failures: u32 = 0;
+        let mut notified_version: Option<String> = None;
+
+        loop {
+            let (enabled, interval) = {
+                let app_state = get_state().lock().await;
+                let settings = &app_state.user_settings;
+                (
+                    settings.update_check_enabled,
+                    check_interval(settings.update_check_interval),
+                )
+            };
+
+            let wait = if failures > 0 {
+                backoff_delay(failures, interval)
+            } else if enabled {
+                interval
+            } else {
+                DISABLED_POLL
+            };
+            tokio::time::sleep(wait).await;
+
+            if !get_state().lock().await.user_settings.update_check_enabled {
+                failures = 0;
+                continue;
+            }
+
+            // ? 캐시가 만료되었을 때만 새로 요청
+            match check_for_update(&app, false).await {
+                Ok(result) => {
+                    failures = 0;
+                    notify_if_new(&app, &result, &mut notified_version);
+                }
+                // ? Frontend 확인과 겹친 경우는 실패가 아니므로 다음 주기에 확인
+                Err(UpdateCheckError::Running) => {}
+                // ? 서명, 파싱 오류는 다시 요청해도 같으므로 다음 주기까지 대기
+                Err(UpdateCheckError::Invalid(e)) => {
+                    failures = 0;
+                    println!("❌ 백그라운드 업데이트 확인 실패: {}", e);
+                }
+                Err(UpdateCheckError::Network(e)) => {
+                    failures += 1;
+                    println!(
+                        "⚠️ 백그라운드 업데이트 확인 실패 ({}회): {} → {:?} 후 재시도",
+                        failures,
+                        e,
+                        backoff_delay(failures, interval)
+                    );
+                }
+            }
+        }
+    });
+}
+
+/// + 새 버전이 확인되면 (버전마다 한 번만) 이벤트 발생
+fn notify_if_new(app: &AppHandle, result: &UpdateCheckResult, notified: &mut Option<String>) {
+    if !result.should_update || notified.as_deref() == Some(result.latest_version.as_str()) {
+        return;
+    }
+
+    println!("🌟 새 버전 확인: v{}", result.latest_version);
+    *notified = Some(result.latest_version.clone());
+    let _ = app.emit(UPDATE_AVAILABLE_EVENT, result.clone());
+}
+
+// > 백그라운드 업데이트 확인
@@ -0,0 +1,69 @@
+use minisign_verify::{PublicKey, Signature};
+use once_cell::sync::Lazy;
+
+// > 업데이트 서명 검증
+// ? latest.json, raids.json, 다운로드 파일은 minisign 서명으로 검증
+// ? 해시는 같은 매니페스트에서 오기 때문에 매니페스트 변조를 막지 못함 → 프로그램에 포함된 공개키로 검증
+
+#[cfg(not(test))]
+static UPDATE_PUBLIC_KEY_TEXT: &str = include_str!("../../keys/update.pub");
+// ? 테스트는 tests/fixtures 서명용 테스트 전용 키로 검증
+#[cfg(test)]
+static UPDATE_PUBLIC_KEY_TEXT: &str = include_str!("../../keys/test.pub");
+
+static UPDATE_PUBLIC_KEY: Lazy<PublicKey> =
+    Lazy::new(|| PublicKey::decode(UPDATE_PUBLIC_KEY_TEXT).expect("update.pub 파싱 실패"));
+
+/// + 서명 파일 주소 (`{url}.minisig`)
+pub fn signature_url(url: &str) -> String {
+    format!("{}.minisig", url)
+}
+
+/// + 프로그램에 포함된 업데이트 공개키
+pub fn update_public_key() -> &'static PublicKey {
+    &UPDATE_PUBLIC_KEY
+}
+
+/// + 서명 오류 메시지
+pub fn invalid_signature(target: &str, reason: impl std::fmt::Display) -> String {
+    format!(
+        "❌ {} 서명이 올바르지 않습니다 (signature invalid): {}",
+        target, reason
+    )
+}
+
+/// + minisign 서명 형식 확인
+pub fn parse_signature(target: &str, signature: &str) -> Result<Signature, String> {
+    if signature.trim().is_empty() {
+        return Err(invalid_signature(target, "서명 없음"));
+    }
+    Signature::decode(signature.trim()).map_err(|e| invalid_signature(target, e))
+}
+
+/// + minisign 서명 검증
+// ? `target`: 오류 메시지에 표시할 검증 대상 이름
+pub fn verify_signature(target: &str, data: &[u8], signature: &str) -> Result<(), String> {
+    let signature = parse_signature(target, signature)?;
+    UPDATE_PUBLIC_KEY
+        .verify(data, &signature, false)
+        .map_err(|e| invalid_signature(target, e))
+}
+
+/// + 서명 파일(`{url}.minisig`) 내용 가져오기
+pub async fn fetch_signature(target: &str, url: &str) -> Result<String, String> {
+    reqwest::get(signature_url(url))
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("{} 서명 요청 실패: {}", target, e))?
+        .text()
+        .await
+        .map_err(|e| format!("{} 서명 읽기 실패: {}", target, e))
+}
+
+/// + 서명 파일을 받아서 데이터 검증
+pub async fn verify_remote_signature(target: &str, url: &str, data: &[u8]) -> Result<(), String> {
+    let signature = fetch_signature(target, url).await?;
+    verify_signature(target, data, &signature)
+}
+
+// > 업데이트 서명 검증
@@ -1,23 +1,48 @@
+use super::download::download_file;
+use super::endpoint::latest_manifest_url;
+use super::rollback::{backup_exe_path, write_update_marker};
+use super::signature::{fetch_signature, verify_signature};
+use crate::state::persist::flush;
+use crate::state::store::get_state;
 use once_cell::sync::Lazy;
 use semver::Version;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use std::{
-    fs::File,
-    io::{BufReader, Read},
-    path::Path,
+    fs,
     process::Command,
     sync::{
         atomic::{AtomicBool, Ordering},
         RwLock,
     },
+    time::{Duration, Instant},
 };
-use tauri::utils::platform::current_exe;
+use tauri::{utils::platform::current_exe, AppHandle};
 use tempfile::Builder;
 
-static UPDATE_CACHE: Lazy<RwLock<Option<UpdateCheckResult>>> = Lazy::new(|| RwLock::new(None));
+/// ? bootstrapper 실행 인자 규약 버전 (bootstrapper는 별도 저장소이므로 인자가 바뀌면 함께 올림)
+// ? v2 인자 (모두 필수):
+// ?   --protocol <버전>           이 값, bootstrapper가 모르는 버전이면 업데이트하지 않고 종료
+// ?   --original-path <경로>      교체할 현재 실행 파일
+// ?   --backup-path <경로>        프로그램이 미리 복사해 둔 이전 실행 파일 (롤백용, bootstrapper는 삭제하지 않음)
+// ?   --update-file <경로>        서명, sha256 검증을 마친 새 실행 파일 (다시 다운로드하지 않고 이 파일로 교체)
+// ?   --update-sha256 <해시>      교체 직전 update-file 확인용
+// ? v1 인자(--update-url, --update-signature)는 bootstrapper가 release 파일을 다시 다운로드하던 방식
+const BOOTSTRAPPER_PROTOCOL: &str = "2";
+
+/// ? 캐시된 결과를 사용할 최대 시간
+const CACHE_TTL: Duration = Duration::from_secs(30 * 60);
+
+static UPDATE_CACHE: Lazy<RwLock<Option<CachedCheck>>> = Lazy::new(|| RwLock::new(None));
 static IS_RUNNING: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
 
+/// * 업데이트 확인 캐시 구조체
+// ? 채널/미러가 바뀌어 latest.json 주소가 달라지면 캐시를 사용하지 않음
+struct CachedCheck {
+    manifest_url: String,
+    checked_at: Instant,
+    result: UpdateCheckResult,
+}
+
 struct ResetGuard;
 
 impl Drop for ResetGuard {
@@ -27,6 +52,29 @@ impl Drop for ResetGuard {
     }
 }
 
+/// * 업데이트 확인 오류 (백그라운드 확인은 네트워크 오류만 짧은 간격으로 재시도)
+#[derive(Debug)]
+pub enum UpdateCheckError {
+    Running,         // 이미 확인 중
+    Network(String), // latest.json, 서명 요청 실패
+    Invalid(String), // 버전, 서명, JSON 오류 (다시 요청해도 같은 결과)
+}
+
+impl std::fmt::Display for UpdateCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateCheckError::Running => write!(f, "이미 업데이트 확인이 진행 중입니다."),
+            UpdateCheckError::Network(e) | UpdateCheckError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<UpdateCheckError> for String {
+    fn from(e: UpdateCheckError) -> Self {
+        e.to_string()
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LatestInfo {
     pub version: String,
@@ -40,6 +88,8 @@ pub struct LatestInfo {
 pub struct DownloadInfo {
     pub url: String,
     pub sha256: String,
+    #[serde(default)]
+    pub signature: String, // minisign 서명 (.minisig 파일 내용)
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -50,76 +100,70 @@ pub struct UpdateCheckResult {
     pub info: LatestInfo,
 }
 
-/// + SHA256 해시 계산
-fn calculate_sha256<P: AsRef<Path>>(path: P) -> Result<String, Box<dyn std::error::Error>> {
-    let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
-    let mut hasher = Sha256::new();
-    let mut buffer = [0u8; 4096];
-
-    loop {
-        let n = reader.read(&mut buffer)?;
-        if n == 0 {
-            break;
-        }
-        hasher.update(&buffer[..n]);
-    }
-
-    Ok(format!("{:x}", hasher.finalize()))
-}
-
-/// + 프로그램 업데이트 확인
-#[tauri::command]
-pub async fn get_update_check_result(
-    app: tauri::AppHandle,
+/// + 프로그램 업데이트 확인 (Frontend 요청, 백그라운드 확인 공용)
+pub async fn check_for_update(
+    app: &AppHandle,
     force_refresh: bool,
-) -> Result<UpdateCheckResult, String> {
+) -> Result<UpdateCheckResult, UpdateCheckError> {
     // * 1. 함수가 이미 실행 중인지 확인
     if IS_RUNNING.swap(true, Ordering::SeqCst) {
         println!("🚫 이미 업데이트 확인 로직이 실행 중입니다.");
-        return Err("이미 업데이트 확인이 진행 중입니다.".into());
+        return Err(UpdateCheckError::Running);
     }
 
     // ? 종료 시 IS_RUNNING을 무조건 false로 되돌리는 안전장치
     let _reset_guard = ResetGuard;
 
+    // ? 사용자 설정(채널, 미러)에 따른 latest.json 주소
+    let manifest_url = latest_manifest_url(&get_state().lock().await.user_settings);
+
     // * 2. 캐시 데이터 확인 (cache 생명주기 제한)
     {
         let cache = UPDATE_CACHE.read().unwrap();
         if !force_refresh {
             // ? force_refresh가 false 일때
-            if let Some(cached_result) = cache.as_ref() {
-                // ? cached_result에 반환(캐시)된 값이 있을때
-                println!("✅ 캐시된 업데이트 결과 반환");
-                return Ok(cached_result.clone());
+            if let Some(cached) = cache.as_ref() {
+                // ? 같은 주소로 반환(캐시)된 값이 있고, 만료되지 않았을때
+                if cached.manifest_url == manifest_url && cached.checked_at.elapsed() < CACHE_TTL {
+                    println!("✅ 캐시된 업데이트 결과 반환");
+                    return Ok(cached.result.clone());
+                }
             }
         }
     }
 
-    println!("🔄 업데이트 정보 새로 요청 중...");
+    println!("🔄 업데이트 정보 새로 요청 중... ({})", manifest_url);
 
     // * 3. 현재 프로그램 버전을 확인
     let current_version = app.package_info().version.to_string();
 
     // * 4. 현재 버전을 파싱
-    let current =
-        Version::parse(&current_version).map_err(|e| format!("현재 버전 파싱 실패: {}", e))?;
+    let current = Version::parse(&current_version)
+        .map_err(|e| UpdateCheckError::Invalid(format!("현재 버전 파싱 실패: {}", e)))?;
 
     // * 5. latest.json 가져오기
-    let response =
-        reqwest::get("https://github.com/CasinGit/LoaAssist/releases/latest/download/latest.json")
-            .await
-            .map_err(|e| format!("요청 실패: {}", e))?;
+    let manifest_bytes = reqwest::get(&manifest_url)
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| UpdateCheckError::Network(format!("요청 실패: {}", e)))?
+        .bytes()
+        .await
+        .map_err(|e| UpdateCheckError::Network(format!("바이트 변환 실패: {}", e)))?;
 
-    // * 6. 가져온 latest.json 데이터를 파싱
-    let info: LatestInfo = response
-        .json()
+    // ? 파싱 전에 latest.json.minisig로 서명 검증
+    let signature = fetch_signature("latest.json", &manifest_url)
         .await
-        .map_err(|e| format!("JSON 파싱 실패: {}", e))?;
+        .map_err(UpdateCheckError::Network)?;
+    verify_signature("latest.json", &manifest_bytes, &signature)
+        .map_err(UpdateCheckError::Invalid)?;
+
+    // * 6. 가져온 latest.json 데이터를 파싱
+    let info: LatestInfo = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| UpdateCheckError::Invalid(format!("JSON 파싱 실패: {}", e)))?;
 
     // * 7. 최신 버전을 파싱
-    let latest =
-        Version::parse(&info.version).map_err(|e| format!("최신 버전 파싱 실패: {}", e))?;
+    let latest = Version::parse(&info.version)
+        .map_err(|e| UpdateCheckError::Invalid(format!("최신 버전 파싱 실패: {}", e)))?;
 
     println!("Server Version : {:?}", latest);
     println!("Current Version : {:?}", current);
@@ -134,15 +178,28 @@ pub async fn get_update_check_result(
     // * 8. 캐시 데이터 갱신
     {
         let mut cache = UPDATE_CACHE.write().unwrap();
-        *cache = Some(result.clone());
+        *cache = Some(CachedCheck {
+            manifest_url,
+            checked_at: Instant::now(),
+            result: result.clone(),
+        });
     }
 
     Ok(result)
 }
 
+/// + 프로그램 업데이트 확인
+#[tauri::command]
+pub async fn get_update_check_result(
+    app: AppHandle,
+    force_refresh: bool,
+) -> Result<UpdateCheckResult, String> {
+    Ok(check_for_update(&app, force_refresh).await?)
+}
+
 /// + 업데이트 실행
 #[tauri::command]
-pub async fn run_update_with_info(info: LatestInfo) -> Result<(), String> {
+pub async fn run_update_with_info(app: AppHandle, info: LatestInfo) -> Result<(), String> {
     // ? 임시 위치에 bootstrapper 다운로드
     let temp_dir = Builder::new()
         .prefix("LoaAssist-")
@@ -150,46 +207,72 @@ pub async fn run_update_with_info(info: LatestInfo) -> Result<(), String> {
         .map_err(|e| format!("임시 디렉토리 생성 실패: {}", e))?;
     let bootstrapper_path = temp_dir.path().join("bootstrapper.exe");
 
-    let bootstrapper_bytes = reqwest::get(&info.bootstrapper.url)
-        .await
-        .map_err(|e| format!("bootstrapper 다운로드 실패: {}", e))?
-        .bytes()
-        .await
-        .map_err(|e| format!("바이트 변환 실패: {}", e))?;
-
-    tokio::fs::write(&bootstrapper_path, &bootstrapper_bytes)
-        .await
-        .map_err(|e| format!("bootstrapper 저장 실패: {}", e))?;
+    // ? 스트리밍 다운로드 (해시, 서명 검증 포함)
+    download_file(
+        &app,
+        "bootstrapper",
+        &info.bootstrapper.url,
+        &bootstrapper_path,
+        &info.bootstrapper.sha256,
+        &info.bootstrapper.signature,
+    )
+    .await?;
 
     println!("Bootstrapper Path : {:?}", bootstrapper_path);
 
-    // ? 해시 검증
-    let actual_hash =
-        calculate_sha256(&bootstrapper_path).map_err(|e| format!("SHA256 계산 실패: {}", e))?;
-    if actual_hash != info.bootstrapper.sha256.to_lowercase() {
-        return Err(format!(
-            "❌ bootstrapper 해시 불일치\n예상: {}\n실제: {}",
-            info.bootstrapper.sha256, actual_hash
-        ));
-    }
+    // ? release 파일을 받아서 프로그램에 포함된 공개키로 검증한 뒤 그 파일을 bootstrapper에 전달
+    let release_path = temp_dir.path().join("release.exe");
+    download_file(
+        &app,
+        "release",
+        &info.release.url,
+        &release_path,
+        &info.release.sha256,
+        &info.release.signature,
+    )
+    .await?;
 
     // ? 현재 실행 경로 파악
     let original_path =
         current_exe().map_err(|e| format!("현재 실행 파일 경로 확인 실패: {}", e))?;
 
+    // ? 롤백에 사용할 이전 실행 파일을 bootstrapper 실행 전에 직접 보관
+    let backup_path = backup_exe_path(&original_path);
+    fs::copy(&original_path, &backup_path)
+        .map_err(|e| format!("이전 실행 파일 백업 실패: {}", e))?;
+
+    // ? 다음 실행 시 정상 실행 여부를 확인할 수 있도록 표시
+    write_update_marker(
+        &app.package_info().version.to_string(),
+        &info.version,
+        &original_path,
+        &backup_path,
+    )?;
+
     // ? bootstrapper 실행
     Command::new(&bootstrapper_path)
         .args([
+            "--protocol",
+            BOOTSTRAPPER_PROTOCOL,
             "--original-path",
             original_path.to_str().unwrap(),
-            "--update-url",
-            &info.release.url,
+            "--backup-path",
+            backup_path.to_str().unwrap(),
+            "--update-file",
+            release_path.to_str().unwrap(),
             "--update-sha256",
             &info.release.sha256,
         ])
         .spawn()
         .map_err(|e| format!("bootstrapper 실행 실패: {}", e))?;
 
+    // ? bootstrapper가 사용할 파일이 있으므로 임시 디렉토리를 삭제하지 않음
+    let _ = temp_dir.keep();
+
     // ! 앱 종료 (bootstrapper에서 종료 확인하고 업데이트 진행함)
+    // ? process::exit는 RunEvent::Exit를 거치지 않으므로 저장되지 않은 상태를 먼저 저장
+    if let Err(e) = flush().await {
+        eprintln!("❌ 종료 시 상태 저장 실패: {}", e);
+    }
     std::process::exit(0);
 }
@@ -1,18 +1,26 @@
+use super::focus_state::{FocusAction, FocusEvent, FocusMachine, SystemClock};
+use super::window_search::find_game_window;
+use super::window_system::{window_system, EventSubscription, SystemEvent};
+use crate::dock::auto_hide::set_pointer_inside;
 use crate::state::store::get_state;
 use enigo::{Enigo, MouseControllable};
-use std::ptr::null_mut;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
 use std::{thread, time::Duration};
-use tauri::WebviewWindow;
-use windows::core::HSTRING;
-use windows::Win32::UI::WindowsAndMessaging::{
-    FindWindowW, GetForegroundWindow, GetWindowTextW, IsIconic, IsWindowVisible,
-    SetForegroundWindow,
-};
+use tauri::{WebviewWindow, WindowEvent};
 
 /// ? 마우스 추적 활성화/비활성화 상태
 static TRACKER_RUNNING: AtomicBool = AtomicBool::new(false);
+/// ? 창이 숨겨져 추적을 일시 중지한 상태
+static TRACKER_SUSPENDED: AtomicBool = AtomicBool::new(false);
+/// ? 추적 스레드 세대 (재시작 시 이전 스레드 종료용)
+static TRACKER_GENERATION: AtomicU64 = AtomicU64::new(0);
+/// ? 추적 스레드를 깨우기 위한 채널
+static TRACKER_SENDER: Mutex<Option<Sender<TrackerMessage>>> = Mutex::new(None);
+
+/// ? OS 이벤트 훅을 지원하지 않는 플랫폼의 확인 주기
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
 /// ? 포커스 자동 이동 활성화/비활성화 상태
 static AUTO_FOCUS_ENABLED: AtomicBool = AtomicBool::new(true);
 
@@ -28,16 +36,40 @@ pub fn resume_auto_focus() {
     println!("✅ 포커스 자동 이동 기능 활성화됨");
 }
 
-/// + 마우스 위치 가져오기
-fn get_mouse_position() -> (i32, i32) {
-    let enigo = Enigo::new();
-    enigo.mouse_location()
+/// + 창이 숨겨진 동안 마우스 추적 일시 중지 (훅 해제)
+#[tauri::command]
+pub fn suspend_mouse_tracking() {
+    if !TRACKER_SUSPENDED.swap(true, Ordering::SeqCst) {
+        wake_tracker();
+    }
+}
+
+/// + 창이 다시 표시되면 마우스 추적 재개
+#[tauri::command]
+pub fn resume_mouse_tracking() {
+    if TRACKER_SUSPENDED.swap(false, Ordering::SeqCst) {
+        wake_tracker();
+    }
 }
 
-/// + 마우스가 창 내부에 있는지 확인
-fn is_mouse_inside_window(window: &WebviewWindow) -> bool {
+/// + 창 최소화/복원에 맞춰 추적 일시 중지/재개 (setup에서 1회 등록)
+// ? 트레이로 숨기는 경우는 hide를 호출하는 쪽에서 직접 suspend 호출
+pub fn watch_window_visibility(window: &WebviewWindow) {
+    let window_clone = window.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Resized(_) = event {
+            if window_clone.is_minimized().unwrap_or(false) {
+                suspend_mouse_tracking();
+            } else if window_clone.is_visible().unwrap_or(false) {
+                resume_mouse_tracking();
+            }
+        }
+    });
+}
+
+/// + 마우스 좌표가 창 내부에 있는지 확인
+fn is_point_inside_window(window: &WebviewWindow, (x, y): (i32, i32)) -> bool {
     if let (Ok(win_pos), Ok(win_size)) = (window.outer_position(), window.outer_size()) {
-        let (x, y) = get_mouse_position();
         return x >= win_pos.x
             && x <= win_pos.x + win_size.width as i32
             && y >= win_pos.y
@@ -48,52 +80,23 @@ fn is_mouse_inside_window(window: &WebviewWindow) -> bool {
 
 /// + 윈도우가 최소화 또는 숨겨진 상태인지 확인
 pub fn is_window_hidden_or_minimized(title: &str) -> bool {
-    let title_wide = HSTRING::from(title);
-
-    unsafe {
-        match FindWindowW(None, &title_wide) {
-            Ok(hwnd) => {
-                let is_minimized = IsIconic(hwnd).as_bool();
-                let is_visible = IsWindowVisible(hwnd).as_bool();
+    let system = window_system();
 
-                if is_minimized || !is_visible {
-                    return true;
-                }
-                false
-            }
-            Err(_) => {
-                println!("❌ 창 '{}' 을(를) 찾을 수 없습니다", title);
-                false
-            }
+    match system.find_window(title) {
+        Some(window) => !window.visible || system.is_minimized(window.id),
+        None => {
+            println!("❌ 창 '{}' 을(를) 찾을 수 없습니다", title);
+            false
         }
     }
 }
 
 /// + 포커스된 창 제목에 숙제표 설정이 포함되어 있는지 확인
 fn is_settings_window_focused() -> bool {
-    unsafe {
-        // 현재 포커스된 창 핸들 가져오기
-        let fg_hwnd = GetForegroundWindow();
-        if fg_hwnd.0 == null_mut() {
-            // 포커스된 창이 없으면 false 반환
-            return false;
-        }
-
-        // 창 제목을 저장할 버퍼 (UTF-16)
-        let mut buf = [0u16; 256];
-        // 제목 길이 가져오기
-        let length = GetWindowTextW(fg_hwnd, &mut buf);
-        if length == 0 {
-            // 제목을 읽을 수 없으면 false
-            return false;
-        }
-
-        // UTF-16 -> Rust String 변환
-        let window_title = String::from_utf16_lossy(&buf[..length as usize]);
-
-        // 설정 창 제목과 비교
-        window_title.contains("숙제표 설정")
-    }
+    // ? 포커스된 창이 없거나 제목을 읽을 수 없으면 false
+    window_system()
+        .foreground_window()
+        .is_some_and(|window| window.title.contains("숙제표 설정"))
 }
 
 /// + 다른 창으로 포커스 변경
@@ -109,109 +112,224 @@ fn focus_other_window() {
     }
 
     let app_state = get_state().blocking_lock();
-    let game_title: HSTRING = HSTRING::from(
-        app_state
-            .user_settings
-            .auto_focus_settings
-            .game_title
-            .clone(),
-    ); // ? 사용자 설정된 게임 제목 사용
+    let focus_settings = app_state.user_settings.auto_focus_settings.clone(); // ? 사용자 설정 사용
     drop(app_state); // ? 상태 락 해제
 
-    unsafe {
-        match FindWindowW(None, &game_title) {
-            Ok(hwnd) => {
-                if SetForegroundWindow(hwnd).as_bool() {
-                    println!(
-                        "✅ \x1b[32m'{}' 창으로 포커스 이동 완료\x1b[0m",
-                        game_title.to_string_lossy()
-                    );
-                } else {
-                    println!("⚠️ '{}' 창 포커스 이동 실패", game_title.to_string_lossy());
-                }
-            }
-            Err(_err) => {
+    let game_title = &focus_settings.game_title;
+    match find_game_window(&focus_settings) {
+        Ok(Some(window)) => {
+            if window_system().focus_window(window.id) {
                 println!(
-                    "❌ '{}' 창을 찾을 수 없습니다",
-                    game_title.to_string_lossy()
+                    "✅ \x1b[32m'{}' 창으로 포커스 이동 완료\x1b[0m",
+                    window.title
                 );
+            } else {
+                println!("⚠️ '{}' 창 포커스 이동 실패", window.title);
             }
         }
+        Ok(None) => {
+            println!("❌ '{}' 창을 찾을 수 없습니다", game_title);
+        }
+        Err(e) => {
+            println!("{} ('{}')", e, game_title);
+        }
     }
 }
 
+/// + 전역 상태에서 `shift_idle_time` 가져오기 (초 단위)
+fn shift_idle_time() -> Duration {
+    let app_state = get_state().blocking_lock();
+    Duration::from_secs(app_state.user_settings.auto_focus_settings.shift_idle_time)
+}
+
+/// + 설정에서 Auto Focus 기능을 켰는지 확인 (자동 숨기기만 켠 경우에도 추적 스레드가 실행됨)
+fn auto_focus_setting_enabled() -> bool {
+    get_state().blocking_lock().user_settings.auto_focus_enabled
+}
+
+/// * 추적 스레드로 전달되는 메시지
+enum TrackerMessage {
+    System(SystemEvent), // OS 훅 이벤트
+    Wake,                // 중지, 일시 중지/재개 상태 다시 확인
+}
+
+/// * 추적 이벤트 공급 방식
+enum EventSource {
+    Hooks { _subscription: EventSubscription }, // OS 이벤트 훅 (drop 시 훅 해제)
+    Polling(Enigo),                             // 훅 미지원 플랫폼 (POLL_INTERVAL 주기 확인)
+}
+
+impl EventSource {
+    /// + OS 훅 구독 (실패 시 주기 확인으로 대체)
+    fn start(sender: &Sender<TrackerMessage>) -> Self {
+        let sender = sender.clone();
+        let subscription = window_system().subscribe(Box::new(move |event| {
+            let _ = sender.send(TrackerMessage::System(event));
+        }));
+
+        match subscription {
+            Some(subscription) => EventSource::Hooks {
+                _subscription: subscription,
+            },
+            None => {
+                println!("⚠️ 창 이벤트 훅을 사용할 수 없어 주기적으로 마우스 위치를 확인합니다.");
+                EventSource::Polling(Enigo::new())
+            }
+        }
+    }
+
+    /// + 다음 메시지를 기다릴 최대 시간 (None: 이벤트가 올 때까지 대기)
+    fn timeout(&self, machine: &FocusMachine<SystemClock>) -> Option<Duration> {
+        match self {
+            EventSource::Hooks { .. } => machine.remaining_idle_time(),
+            EventSource::Polling(_) => Some(POLL_INTERVAL),
+        }
+    }
+}
+
+/// + 추적 스레드 깨우기
+fn wake_tracker() {
+    if let Some(sender) = TRACKER_SENDER.lock().unwrap().as_ref() {
+        let _ = sender.send(TrackerMessage::Wake);
+    }
+}
+
+/// + 메시지 수신 (Ok(None): 시간 초과, Err: 채널 종료)
+fn receive(
+    receiver: &Receiver<TrackerMessage>,
+    timeout: Option<Duration>,
+) -> Result<Option<TrackerMessage>, ()> {
+    match timeout {
+        Some(timeout) => match receiver.recv_timeout(timeout) {
+            Ok(message) => Ok(Some(message)),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(()),
+        },
+        None => receiver.recv().map(Some).map_err(|_| ()),
+    }
+}
+
+/// + 밀려 있는 이벤트 처리 후 마지막 마우스 위치 반환
+// ? 마우스 이동은 초당 수백 번 발생할 수 있어서 중간 위치는 건너뜀
+fn drain_pending(
+    receiver: &Receiver<TrackerMessage>,
+    machine: &mut FocusMachine<SystemClock>,
+    mut position: (i32, i32),
+) -> (i32, i32) {
+    while let Ok(message) = receiver.try_recv() {
+        match message {
+            TrackerMessage::System(SystemEvent::MouseMoved { x, y }) => position = (x, y),
+            TrackerMessage::System(SystemEvent::ForegroundChanged) => {
+                machine.handle(FocusEvent::SettingsFocused(is_settings_window_focused()));
+            }
+            TrackerMessage::Wake => {} // ? 중지/일시 중지 상태는 루프 시작에서 다시 확인
+        }
+    }
+    position
+}
+
 /// + 마우스 추적 시작 (전역 상태에서 `shift_idle_time` 사용)
+// ? OS 훅 이벤트가 올 때만 깨어나고, 창이 숨겨진 동안은 훅을 해제하고 대기
 pub fn start_mouse_tracking(window: WebviewWindow) {
-    if TRACKER_RUNNING.load(Ordering::SeqCst) {
+    if TRACKER_RUNNING.swap(true, Ordering::SeqCst) {
         return; // ? 이미 실행 중이면 중복 실행 방지
     }
-    TRACKER_RUNNING.store(true, Ordering::SeqCst);
+    let generation = TRACKER_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    let (sender, receiver) = mpsc::channel();
+    *TRACKER_SENDER.lock().unwrap() = Some(sender.clone());
 
     thread::spawn(move || {
-        let mut is_mouse_over = false;
-        let mut last_out_time: Option<Instant> = None;
-
-        while TRACKER_RUNNING.load(Ordering::SeqCst) {
-            let inside = is_mouse_inside_window(&window);
-            let settings_active = is_settings_window_focused(); // settings 창 확인
-
-            if inside {
-                if !is_mouse_over {
-                    is_mouse_over = true;
-                    // let _ = window.set_ignore_cursor_events(false); // ? 클릭 스루 비활성화
-
-                    if !settings_active {
-                        // ? 설정 창이 포커스 되어있지 않을 때만 포커스 이동
-                        let _ = window.set_focus();
-                        println!("▶ 메인 창에 포커스 이동");
-                    } else {
-                        println!(
-                            "🔒 설정 창 활성화 중, 메인 창 \x1b[31m포커스 이동 즉시 차단\x1b[0m"
-                        );
-                    }
+        let is_current = || {
+            TRACKER_RUNNING.load(Ordering::SeqCst)
+                && TRACKER_GENERATION.load(Ordering::SeqCst) == generation
+        };
+        let mut machine = FocusMachine::new(SystemClock, shift_idle_time());
+        let mut active_source: Option<EventSource> = None;
+
+        while is_current() {
+            if TRACKER_SUSPENDED.load(Ordering::SeqCst) {
+                if active_source.take().is_some() {
+                    machine.suspend();
+                    println!("💤 창이 숨겨져 마우스 추적 일시 중지");
                 }
-                last_out_time = None; // ? 다시 창 안으로 들어오면 타이머 초기화
-            } else {
-                if is_mouse_over {
-                    is_mouse_over = false;
-                    // let _ = window.set_ignore_cursor_events(true); // ? 클릭 스루 활성화
-
-                    if !settings_active {
-                        // 설정 창 비활성화 상태면 대기 시작
-                        last_out_time = Some(Instant::now()); // ? 창을 떠난 시간 기록
-                        println!("◀ 창 밖으로 나감: 입력 차단 (대기 중)");
-                    } else {
-                        // 설정 창 활성화 중이면 즉시 포커스 이동 차단
-                        println!("🔒 설정 창 활성화 중, \x1b[31m포커스 이동 즉시 차단\x1b[0m");
-                        last_out_time = None; // 타이머 초기화
-                    }
-                } else if let Some(out_time) = last_out_time {
-                    // ? 전역 상태에서 `shift_idle_time` 가져오기 (초 단위)
-                    let shift_idle_time = {
-                        let app_state = get_state().blocking_lock();
-                        Duration::from_secs(
-                            app_state.user_settings.auto_focus_settings.shift_idle_time,
-                        ) // ? 초 단위 사용
-                    };
-
-                    // ? 설정된 대기시간이 지나면 포커스 변경
-                    if out_time.elapsed() >= shift_idle_time {
-                        if !settings_active {
-                            focus_other_window();
-                        } else {
-                            println!("🔒 설정 창 활성화 중이라 포커스 이동 차단");
-                        }
-                        last_out_time = None; // ? 포커스 변경 후 타이머 초기화
-                    }
+                // ? 재개, 중지 신호가 올 때까지 대기
+                match receiver.recv() {
+                    Ok(_) => continue,
+                    Err(_) => break,
+                }
+            }
+
+            let source = active_source.get_or_insert_with(|| {
+                // ? 구독 시작 시점의 설정 창 포커스 상태 반영
+                machine.handle(FocusEvent::SettingsFocused(is_settings_window_focused()));
+                EventSource::start(&sender)
+            });
+
+            if machine.is_waiting() {
+                machine.set_shift_idle_time(shift_idle_time());
+            }
+
+            let message = match receive(&receiver, source.timeout(&machine)) {
+                Ok(message) => message,
+                Err(()) => break,
+            };
+
+            let event = match (message, &mut *source) {
+                (Some(TrackerMessage::Wake), _) => continue,
+                (Some(TrackerMessage::System(SystemEvent::ForegroundChanged)), _) => {
+                    // ? settings 창 확인
+                    machine.handle(FocusEvent::SettingsFocused(is_settings_window_focused()));
+                    continue;
                 }
+                (Some(TrackerMessage::System(SystemEvent::MouseMoved { x, y })), _) => {
+                    let position = drain_pending(&receiver, &mut machine, (x, y));
+                    machine.mouse_event(is_point_inside_window(&window, position))
+                }
+                (None, EventSource::Polling(enigo)) => {
+                    machine.handle(FocusEvent::SettingsFocused(is_settings_window_focused()));
+                    machine.mouse_event(is_point_inside_window(&window, enigo.mouse_location()))
+                }
+                // ? 대기 시간 경과
+                (None, EventSource::Hooks { .. }) => FocusEvent::Tick,
+            };
+
+            // ? 도킹 자동 숨기기에 마우스 위치 전달
+            match event {
+                FocusEvent::MouseEntered => set_pointer_inside(true),
+                FocusEvent::MouseLeft => set_pointer_inside(false),
+                _ => {}
             }
 
-            thread::sleep(Duration::from_millis(100));
+            match (event, machine.handle(event)) {
+                (_, Some(FocusAction::FocusOverlay | FocusAction::FocusGame))
+                    if !auto_focus_setting_enabled() => {}
+                (_, Some(FocusAction::FocusOverlay)) => {
+                    let _ = window.set_focus();
+                    println!("▶ 메인 창에 포커스 이동");
+                }
+                (_, Some(FocusAction::FocusGame)) => focus_other_window(),
+                (FocusEvent::MouseEntered, Some(FocusAction::Blocked)) => {
+                    println!("🔒 설정 창 활성화 중, 메인 창 \x1b[31m포커스 이동 즉시 차단\x1b[0m");
+                }
+                (FocusEvent::MouseLeft, Some(FocusAction::Blocked)) => {
+                    println!("🔒 설정 창 활성화 중, \x1b[31m포커스 이동 즉시 차단\x1b[0m");
+                }
+                (_, Some(FocusAction::Blocked)) => {
+                    println!("🔒 설정 창 활성화 중이라 포커스 이동 차단");
+                }
+                (FocusEvent::MouseLeft, None) => {
+                    println!("◀ 창 밖으로 나감: 입력 차단 (대기 중)");
+                }
+                _ => {}
+            }
         }
+        // ? source drop 시 OS 훅 해제
     });
 }
 
 /// + 마우스 추적 중지
 pub fn stop_mouse_tracking() {
     TRACKER_RUNNING.store(false, Ordering::SeqCst);
+    wake_tracker();
 }
@@ -0,0 +1,294 @@
+use std::time::{Duration, Instant};
+
+// > Auto Focus 상태 머신
+// ? 마우스 위치, 설정 창 포커스 여부를 이벤트로 받아서 어떤 창에 포커스할지만 결정 (창 제어 X)
+// ? 시간은 Clock으로 주입받아서 실제 대기 없이 shift_idle_time 동작 확인 가능
+
+/// * 현재 시간 제공 trait
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// * 시스템 시계
+#[derive(Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// * 상태 머신 입력 이벤트
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusEvent {
+    MouseEntered,          // 마우스가 메인 창 안으로 들어옴
+    MouseLeft,             // 마우스가 메인 창 밖으로 나감
+    Tick,                  // 주기적인 시간 경과 확인
+    SettingsFocused(bool), // 숙제표 설정 창 포커스 여부 변경
+}
+
+/// * 상태 머신 결과
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusAction {
+    FocusOverlay, // 메인 창에 포커스
+    FocusGame,    // 게임 창으로 포커스 이동
+    Blocked,      // 설정 창이 활성화되어 있어 포커스 이동 차단
+}
+
+/// * 마우스 위치 상태
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusState {
+    Inside,
+    Outside { left_at: Option<Instant> }, // 창을 떠난 시간 (대기 중이 아니면 None)
+}
+
+/// * Auto Focus 상태 머신 구조체
+pub struct FocusMachine<C: Clock> {
+    clock: C,
+    state: FocusState,
+    settings_focused: bool,
+    shift_idle_time: Duration,
+}
+
+impl<C: Clock> FocusMachine<C> {
+    pub fn new(clock: C, shift_idle_time: Duration) -> Self {
+        Self {
+            clock,
+            state: FocusState::Outside { left_at: None },
+            settings_focused: false,
+            shift_idle_time,
+        }
+    }
+
+    pub fn state(&self) -> FocusState {
+        self.state
+    }
+
+    /// + 게임 창으로 포커스 이동을 기다리는 중인지 확인
+    pub fn is_waiting(&self) -> bool {
+        matches!(self.state, FocusState::Outside { left_at: Some(_) })
+    }
+
+    /// + 게임 창으로 포커스를 옮기기까지 남은 시간 (대기 중이 아니면 None)
+    pub fn remaining_idle_time(&self) -> Option<Duration> {
+        match self.state {
+            FocusState::Outside { left_at: Some(at) } => Some(
+                self.shift_idle_time
+                    .saturating_sub(self.clock.now().duration_since(at)),
+            ),
+            _ => None,
+        }
+    }
+
+    /// + 창 밖으로 나간 뒤 게임 창으로 포커스를 옮기기까지 대기 시간
+    pub fn set_shift_idle_time(&mut self, shift_idle_time: Duration) {
+        self.shift_idle_time = shift_idle_time;
+    }
+
+    /// + 마우스가 창 안에 있는지 여부로 이벤트 결정 (위치가 바뀌지 않았다면 Tick)
+    pub fn mouse_event(&self, inside: bool) -> FocusEvent {
+        match (inside, self.state) {
+            (true, FocusState::Outside { .. }) => FocusEvent::MouseEntered,
+            (false, FocusState::Inside) => FocusEvent::MouseLeft,
+            _ => FocusEvent::Tick,
+        }
+    }
+
+    /// + 창이 숨겨져 추적을 일시 중지할 때 호출 (대기 중이던 포커스 이동 취소)
+    // ? 숨겨진 동안 마우스는 창 안에 있을 수 없으므로 다시 표시된 뒤 첫 위치부터 판단
+    pub fn suspend(&mut self) {
+        self.state = FocusState::Outside { left_at: None };
+    }
+
+    /// + 이벤트 처리 후 실행할 동작 반환
+    pub fn handle(&mut self, event: FocusEvent) -> Option<FocusAction> {
+        match (event, self.state) {
+            (FocusEvent::SettingsFocused(focused), _) => {
+                self.settings_focused = focused;
+                None
+            }
+
+            (FocusEvent::MouseEntered, FocusState::Outside { .. }) => {
+                self.state = FocusState::Inside;
+                // ? 설정 창이 포커스 되어있지 않을 때만 메인 창으로 포커스 이동
+                Some(self.unless_settings(FocusAction::FocusOverlay))
+            }
+
+            (FocusEvent::MouseLeft, FocusState::Inside) => {
+                if self.settings_focused {
+                    // ? 설정 창 활성화 중이면 대기 없이 즉시 차단
+                    self.state = FocusState::Outside { left_at: None };
+                    return Some(FocusAction::Blocked);
+                }
+                self.state = FocusState::Outside {
+                    left_at: Some(self.clock.now()),
+                };
+                None
+            }
+
+            (FocusEvent::Tick, FocusState::Outside { left_at: Some(at) }) => {
+                if self.clock.now().duration_since(at) < self.shift_idle_time {
+                    return None;
+                }
+                // ? 대기 시간이 지나면 타이머 초기화 (포커스 이동은 1회만)
+                self.state = FocusState::Outside { left_at: None };
+                Some(self.unless_settings(FocusAction::FocusGame))
+            }
+
+            // ? 이미 같은 상태이거나 대기 중이 아닐 때
+            _ => None,
+        }
+    }
+
+    fn unless_settings(&self, action: FocusAction) -> FocusAction {
+        if self.settings_focused {
+            FocusAction::Blocked
+        } else {
+            action
+        }
+    }
+}
+
+// > Auto Focus 상태 머신
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// * 직접 시간을 진행시키는 테스트용 시계
+    #[derive(Clone)]
+    struct FakeClock {
+        start: Instant,
+        elapsed: Rc<Cell<Duration>>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                elapsed: Rc::new(Cell::new(Duration::ZERO)),
+            }
+        }
+
+        fn advance(&self, secs: u64) {
+            self.elapsed
+                .set(self.elapsed.get() + Duration::from_secs(secs));
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.start + self.elapsed.get()
+        }
+    }
+
+    const IDLE: Duration = Duration::from_secs(5);
+
+    fn machine() -> (FocusMachine<FakeClock>, FakeClock) {
+        let clock = FakeClock::new();
+        (FocusMachine::new(clock.clone(), IDLE), clock)
+    }
+
+    #[test]
+    fn enter_focuses_overlay_and_leave_starts_waiting() {
+        let (mut machine, clock) = machine();
+
+        assert_eq!(machine.mouse_event(true), FocusEvent::MouseEntered);
+        assert_eq!(
+            machine.handle(FocusEvent::MouseEntered),
+            Some(FocusAction::FocusOverlay)
+        );
+        assert_eq!(machine.state(), FocusState::Inside);
+        assert_eq!(machine.mouse_event(true), FocusEvent::Tick);
+
+        assert_eq!(machine.handle(FocusEvent::MouseLeft), None);
+        assert_eq!(
+            machine.state(),
+            FocusState::Outside {
+                left_at: Some(clock.now())
+            }
+        );
+        assert!(machine.is_waiting());
+    }
+
+    #[test]
+    fn tick_before_idle_time_does_nothing() {
+        let (mut machine, clock) = machine();
+        machine.handle(FocusEvent::MouseEntered);
+        machine.handle(FocusEvent::MouseLeft);
+
+        clock.advance(3);
+        assert_eq!(machine.handle(FocusEvent::Tick), None);
+        assert_eq!(machine.remaining_idle_time(), Some(Duration::from_secs(2)));
+
+        // ? 대기 중에 다시 들어오면 게임 창으로 이동하지 않음
+        assert_eq!(
+            machine.handle(FocusEvent::MouseEntered),
+            Some(FocusAction::FocusOverlay)
+        );
+        clock.advance(10);
+        assert_eq!(machine.handle(FocusEvent::Tick), None);
+    }
+
+    #[test]
+    fn tick_after_idle_time_focuses_game_once() {
+        let (mut machine, clock) = machine();
+        machine.handle(FocusEvent::MouseEntered);
+        machine.handle(FocusEvent::MouseLeft);
+
+        clock.advance(5);
+        assert_eq!(
+            machine.handle(FocusEvent::Tick),
+            Some(FocusAction::FocusGame)
+        );
+        assert_eq!(machine.state(), FocusState::Outside { left_at: None });
+        assert_eq!(machine.handle(FocusEvent::Tick), None);
+    }
+
+    #[test]
+    fn settings_window_blocks_focus_moves() {
+        let (mut machine, clock) = machine();
+        machine.handle(FocusEvent::SettingsFocused(true));
+
+        assert_eq!(
+            machine.handle(FocusEvent::MouseEntered),
+            Some(FocusAction::Blocked)
+        );
+        // ? 설정 창 활성화 중 나가면 대기 없이 즉시 차단
+        assert_eq!(
+            machine.handle(FocusEvent::MouseLeft),
+            Some(FocusAction::Blocked)
+        );
+        assert!(!machine.is_waiting());
+
+        // ? 대기 중에 설정 창이 포커스되면 시간이 지나도 차단
+        machine.handle(FocusEvent::SettingsFocused(false));
+        machine.handle(FocusEvent::MouseEntered);
+        machine.handle(FocusEvent::MouseLeft);
+        machine.handle(FocusEvent::SettingsFocused(true));
+        clock.advance(5);
+        assert_eq!(machine.handle(FocusEvent::Tick), Some(FocusAction::Blocked));
+    }
+
+    #[test]
+    fn suspend_cancels_waiting_and_resume_starts_fresh() {
+        let (mut machine, clock) = machine();
+        machine.handle(FocusEvent::MouseEntered);
+        machine.handle(FocusEvent::MouseLeft);
+
+        machine.suspend();
+        clock.advance(30);
+        assert_eq!(machine.handle(FocusEvent::Tick), None);
+        assert_eq!(machine.remaining_idle_time(), None);
+
+        // ? 다시 표시된 뒤 첫 위치부터 판단
+        assert_eq!(machine.mouse_event(true), FocusEvent::MouseEntered);
+        assert_eq!(
+            machine.handle(FocusEvent::MouseEntered),
+            Some(FocusAction::FocusOverlay)
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use super::title_match::{title_prefix, TitleMatcher};
+use super::window_system::{window_system, WindowId, WindowSystem};
+use crate::state::persist::mark_dirty;
+use crate::state::store::get_state;
+use crate::state::types::{FocusSettings, TitleMatchMode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+// > 게임 클라이언트 자동 감지
+// ? 실행 파일 이름(LOSTARK.exe)으로 게임 프로세스를 찾고, 그 프로세스의 최상위 창 제목으로 game_title 갱신
+// ? 클라이언트가 켜지고 꺼질 때 game://detected, game://exited 이벤트 발생
+
+pub const GAME_DETECTED_EVENT: &str = "game://detected";
+pub const GAME_EXITED_EVENT: &str = "game://exited";
+
+/// ? 기본 게임 실행 파일 이름 (비교 방식이 Process면 사용자가 지정한 이름 사용)
+const GAME_PROCESS_NAME: &str = "LOSTARK.exe";
+/// ? 게임 프로세스 확인 주기
+const DETECT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// ? 현재 감지된 게임 창 (Frontend가 이벤트보다 늦게 준비된 경우 조회용)
+static DETECTED_GAME: Mutex<Option<GameWindow>> = Mutex::new(None);
+
+/// * 감지된 게임 창 정보 구조체
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct GameWindow {
+    pub window_id: WindowId,
+    pub pid: u32,
+    pub process: String, // 실행 파일 이름
+    pub title: String,   // 창 제목
+}
+
+/// * 게임 프로세스 탐색 구조체
+// ? pid별 프로세스 이름 확인 결과를 저장해서 매 주기마다 모든 프로세스를 다시 조회하지 않음
+#[derive(Default)]
+struct GameDetector {
+    process_name: String,        // 찾을 실행 파일 이름
+    is_game: HashMap<u32, bool>, // pid → 게임 프로세스 여부
+}
+
+impl GameDetector {
+    /// + 보이는 최상위 창 중 게임 프로세스 창 찾기
+    fn scan(&mut self, system: &dyn WindowSystem, process_name: &str) -> Option<GameWindow> {
+        if !self.process_name.eq_ignore_ascii_case(process_name) {
+            self.process_name = process_name.to_string();
+            self.is_game.clear();
+        }
+
+        let windows: Vec<_> = system
+            .enumerate_windows()
+            .into_iter()
+            .filter(|window| window.visible)
+            .collect();
+
+        // ? 종료된 프로세스는 캐시에서 제거 (pid 재사용 대비)
+        self.is_game
+            .retain(|pid, _| windows.iter().any(|window| window.pid == Some(*pid)));
+
+        windows.into_iter().find_map(|window| {
+            let pid = window.pid?;
+            let is_game = *self.is_game.entry(pid).or_insert_with(|| {
+                system
+                    .process_name(pid)
+                    .is_some_and(|name| name.eq_ignore_ascii_case(&self.process_name))
+            });
+
+            is_game.then(|| GameWindow {
+                window_id: window.id,
+                pid,
+                process: self.process_name.clone(),
+                title: window.title,
+            })
+        })
+    }
+}
+
+/// + 감지한 창으로 저장할 game_title (바꿀 필요가 없으면 None)
+// ? 정규식, 실행 파일 이름 비교는 사용자가 입력한 패턴을 유지
+// ? 시작 부분 비교는 버전이 바뀌어도 찾을 수 있도록 버전 표시를 뺀 앞부분만 저장
+// ? 현재 game_title이 이미 감지한 창과 일치하면 사용자가 입력한 값을 유지
+fn detected_title(settings: &FocusSettings, game: &GameWindow) -> Option<String> {
+    let already_matches = TitleMatcher::new(settings.match_mode, &settings.game_title)
+        .is_ok_and(|matcher| matcher.matches(&game.title, Some(&game.process)));
+    if already_matches {
+        return None;
+    }
+
+    let title = match settings.match_mode {
+        TitleMatchMode::Exact => game.title.as_str(),
+        TitleMatchMode::Prefix => title_prefix(&game.title),
+        _ => return None,
+    };
+    (settings.game_title != title).then(|| title.to_string())
+}
+
+/// + 감지한 창 제목으로 game_title 갱신 (변경된 경우 true)
+async fn update_game_title(game: &GameWindow) -> bool {
+    let mut app_state = get_state().lock().await;
+    let settings = &mut app_state.user_settings.auto_focus_settings;
+
+    let Some(title) = detected_title(settings, game) else {
+        return false;
+    };
+    settings.game_title = title;
+    mark_dirty();
+    true
+}
+
+/// + 게임 클라이언트 감지 시작
+pub fn start_game_detection(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut detector = GameDetector::default();
+
+        loop {
+            let (enabled, process_name) = {
+                let app_state = get_state().lock().await;
+                let settings = &app_state.user_settings;
+                let focus = &settings.auto_focus_settings;
+                let process_name = match focus.match_mode {
+                    TitleMatchMode::Process => focus.game_title.trim().to_string(),
+                    _ => GAME_PROCESS_NAME.to_string(),
+                };
+                (settings.auto_detect_title, process_name)
+            };
+
+            let found = if enabled {
+                // ? 창 목록, 프로세스 조회는 블로킹 호출이라 별도 스레드에서 실행
+                let result = tauri::async_runtime::spawn_blocking(move || {
+                    let found = detector.scan(window_system(), &process_name);
+                    (detector, found)
+                })
+                .await;
+
+                match result {
+                    Ok((returned, found)) => {
+                        detector = returned;
+                        found
+                    }
+                    Err(e) => {
+                        eprintln!("❌ 게임 프로세스 확인 실패: {}", e);
+                        detector = GameDetector::default();
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+
+            let previous = DETECTED_GAME.lock().unwrap().clone();
+            match (previous, &found) {
+                // ? 같은 클라이언트 창이 계속 실행 중
+                (Some(previous), Some(game))
+                    if previous.pid == game.pid && previous.title == game.title => {}
+                // ? 새로 실행되었거나 창 제목이 바뀜 (로딩 후 버전 표시 등)
+                (_, Some(game)) => {
+                    let title_note = match update_game_title(game).await {
+                        true => " → 창 이름 갱신",
+                        false => "",
+                    };
+                    println!(
+                        "🎮 게임 클라이언트 감지: '{}' ({}, pid {}){}",
+                        game.title, game.process, game.pid, title_note
+                    );
+                    let _ = app.emit(GAME_DETECTED_EVENT, game.clone());
+                }
+                (Some(previous), None) => {
+                    println!(
+                        "🎮 게임 클라이언트 종료: '{}' (pid {})",
+                        previous.title, previous.pid
+                    );
+                    let _ = app.emit(GAME_EXITED_EVENT, previous);
+                }
+                (None, None) => {}
+            }
+            *DETECTED_GAME.lock().unwrap() = found;
+
+            tokio::time::sleep(DETECT_INTERVAL).await;
+        }
+    });
+}
+
+// > 게임 클라이언트 자동 감지
+// ////////////////////////////////////////////////////////////////////
+// > Tauri Invoke Functions
+
+/// + 현재 감지된 게임 창 (없으면 None)
+#[tauri::command]
+pub fn get_detected_game() -> Option<GameWindow> {
+    DETECTED_GAME.lock().unwrap().clone()
+}
+
+// > Tauri Invoke Functions
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window_utils::window_system::fake::FakeWindowSystem;
+
+    const GAME_TITLE: &str = "LOST ARK (64-bit, DX11) v.3.5.7.1";
+
+    fn game(title: &str) -> GameWindow {
+        GameWindow {
+            window_id: 1,
+            pid: 100,
+            process: GAME_PROCESS_NAME.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    fn settings(match_mode: TitleMatchMode, game_title: &str) -> FocusSettings {
+        FocusSettings {
+            game_title: game_title.to_string(),
+            match_mode,
+            ..FocusSettings::default()
+        }
+    }
+
+    #[test]
+    fn scan_follows_process_start_and_exit() {
+        let system = FakeWindowSystem::default();
+        let mut detector = GameDetector::default();
+        system.add_window(2, "LOST ARK 공략 - Chrome", Some(200));
+        system.set_process_name(200, Some("chrome.exe"));
+        assert_eq!(detector.scan(&system, GAME_PROCESS_NAME), None);
+
+        // ? 게임 실행
+        system.add_window(1, GAME_TITLE, Some(100));
+        system.set_process_name(100, Some("LostArk.exe")); // ? 대소문자 무시
+        assert_eq!(
+            detector.scan(&system, GAME_PROCESS_NAME),
+            Some(game(GAME_TITLE))
+        );
+
+        // ? 게임 종료
+        system.remove_window(1);
+        system.set_process_name(100, None);
+        assert_eq!(detector.scan(&system, GAME_PROCESS_NAME), None);
+        assert!(!detector.is_game.contains_key(&100));
+    }
+
+    #[test]
+    fn scan_skips_hidden_windows_and_reused_pids() {
+        let system = FakeWindowSystem::default();
+        let mut detector = GameDetector::default();
+        system.add_window(1, GAME_TITLE, Some(100));
+        system.set_process_name(100, Some(GAME_PROCESS_NAME));
+        system.set_visible(1, false);
+        assert_eq!(detector.scan(&system, GAME_PROCESS_NAME), None);
+
+        system.set_visible(1, true);
+        assert!(detector.scan(&system, GAME_PROCESS_NAME).is_some());
+
+        // ? 게임이 종료되고 같은 pid를 다른 프로세스가 사용
+        system.remove_window(1);
+        assert_eq!(detector.scan(&system, GAME_PROCESS_NAME), None);
+        system.add_window(3, "메모장", Some(100));
+        system.set_process_name(100, Some("notepad.exe"));
+        assert_eq!(detector.scan(&system, GAME_PROCESS_NAME), None);
+    }
+
+    #[test]
+    fn matching_title_is_not_rewritten() {
+        let detected = game(GAME_TITLE);
+
+        for settings in [
+            FocusSettings::default(),
+            settings(TitleMatchMode::Prefix, "LOST ARK"),
+            settings(TitleMatchMode::Exact, GAME_TITLE),
+        ] {
+            assert_eq!(detected_title(&settings, &detected), None);
+        }
+    }
+
+    #[test]
+    fn outdated_title_is_replaced_for_title_modes() {
+        let detected = game(GAME_TITLE);
+
+        assert_eq!(
+            detected_title(&settings(TitleMatchMode::Prefix, "LOST ARK v."), &detected),
+            Some("LOST ARK (".to_string())
+        );
+        assert_eq!(
+            detected_title(
+                &settings(TitleMatchMode::Exact, "LOST ARK (64-bit, DX11) v.3.5.6.0"),
+                &detected
+            ),
+            Some(GAME_TITLE.to_string())
+        );
+        // ? 정규식, 포함, 실행 파일 이름은 사용자가 입력한 패턴 유지
+        for mode in [
+            TitleMatchMode::Regex,
+            TitleMatchMode::Contains,
+            TitleMatchMode::Process,
+        ] {
+            assert_eq!(detected_title(&settings(mode, "메모장"), &detected), None);
+        }
+    }
+}
@@ -1,3 +1,7 @@
 pub mod auto_focus_shift;
+pub mod focus_state;
+pub mod game_detect;
+pub mod title_match;
 pub mod window_os_info;
 pub mod window_search;
+pub mod window_system;
@@ -0,0 +1,120 @@
+use crate::state::types::TitleMatchMode;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// > 게임 창 이름 비교
+// ? Auto Focus 대상 창 찾기, find_window_by_title, 미리보기 커맨드에서 같은 비교 방식 사용
+
+/// ? 창 제목 끝의 클라이언트 정보, 버전 표시 (예: " (64-bit, DX11) v.3.5.7.1")
+static VERSION_SUFFIX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:\s*\([^()]*\))?\s*v\.\d+(?:\.\d+)*\s*$").unwrap());
+
+/// + 창 제목에서 버전 번호를 뺀 앞부분 (시작 부분 비교용, 버전 표시가 없으면 그대로)
+// ? "LOST ARK (64-bit, DX11) v.3.5.7.1" → "LOST ARK (", "LOST ARK v.3.6.0.2" → "LOST ARK v."
+// ? 게임 이름만 남기면 같은 이름으로 시작하는 브라우저 창 등도 일치하므로 여는 괄호 또는 "v."까지 남김
+pub fn title_prefix(title: &str) -> &str {
+    let title = title.trim();
+    let suffix = match VERSION_SUFFIX.find(title) {
+        Some(suffix) if suffix.start() > 0 => &title[suffix.start()..],
+        _ => return title,
+    };
+
+    let keep = match suffix.find('(') {
+        Some(open) => open + 1,
+        None => suffix.find("v.").map_or(0, |v| v + 2),
+    };
+    &title[..title.len() - suffix.len() + keep]
+}
+
+/// * 창 이름 비교 구조체
+pub struct TitleMatcher {
+    mode: TitleMatchMode,
+    pattern: String,
+    regex: Option<Regex>, // Regex 모드일 때만 컴파일
+}
+
+impl TitleMatcher {
+    /// + 비교 방식과 패턴으로 생성 (정규식이 올바르지 않으면 Err)
+    pub fn new(mode: TitleMatchMode, pattern: &str) -> Result<Self, String> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err("❌ 창 이름이 비어 있습니다".to_string());
+        }
+
+        let regex = match mode {
+            TitleMatchMode::Regex => Some(
+                Regex::new(pattern).map_err(|e| format!("❌ 정규식이 올바르지 않습니다: {}", e))?,
+            ),
+            _ => None,
+        };
+
+        Ok(Self {
+            mode,
+            pattern: pattern.to_string(),
+            regex,
+        })
+    }
+
+    /// + 실행 파일 이름이 필요한 비교 방식인지 확인
+    pub fn needs_process(&self) -> bool {
+        self.mode == TitleMatchMode::Process
+    }
+
+    /// + 창 제목(또는 실행 파일 이름)이 조건에 맞는지 확인
+    // ? 실행 파일 이름은 대소문자 구분 없이 비교 (LOSTARK.exe == lostark.exe)
+    pub fn matches(&self, title: &str, process: Option<&str>) -> bool {
+        match self.mode {
+            TitleMatchMode::Exact => title == self.pattern,
+            TitleMatchMode::Prefix => title.starts_with(&self.pattern),
+            TitleMatchMode::Contains => title.contains(&self.pattern),
+            TitleMatchMode::Regex => self.regex.as_ref().is_some_and(|re| re.is_match(title)),
+            TitleMatchMode::Process => {
+                process.is_some_and(|name| name.eq_ignore_ascii_case(&self.pattern))
+            }
+        }
+    }
+}
+
+// > 게임 창 이름 비교
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::types::FocusSettings;
+
+    #[test]
+    fn title_prefix_strips_version_suffix() {
+        assert_eq!(
+            title_prefix("LOST ARK (64-bit, DX11) v.3.5.7.1"),
+            "LOST ARK ("
+        );
+        assert_eq!(title_prefix("LOST ARK v.3.6.0.2"), "LOST ARK v.");
+        assert_eq!(title_prefix(" LOST ARK "), "LOST ARK");
+        assert_eq!(title_prefix("메모장"), "메모장");
+        assert_eq!(title_prefix("v.1.0"), "v.1.0"); // ? 남는 부분이 없으면 그대로
+    }
+
+    #[test]
+    fn prefix_of_title_still_matches_new_versions() {
+        let matcher = TitleMatcher::new(
+            TitleMatchMode::Prefix,
+            title_prefix("LOST ARK (64-bit, DX11) v.3.5.7.1"),
+        )
+        .unwrap();
+
+        assert!(matcher.matches("LOST ARK (64-bit, DX11) v.3.6.0.2", None));
+        assert!(matcher.matches("LOST ARK (64-bit, DX12) v.3.6.0.2", None));
+        assert!(!matcher.matches("LOST ARK 공략 - Chrome", None));
+        assert!(!matcher.matches("메모장", None));
+    }
+
+    #[test]
+    fn default_title_skips_browser_windows() {
+        let settings = FocusSettings::default();
+        let matcher = TitleMatcher::new(settings.match_mode, &settings.game_title).unwrap();
+
+        assert!(matcher.matches("LOST ARK (64-bit, DX11) v.3.5.7.1", Some("LOSTARK.exe")));
+        assert!(!matcher.matches("LOST ARK 공략 - Chrome", Some("chrome.exe")));
+        assert!(!matcher.matches("LOST ARK - 인벤", Some("msedge.exe")));
+    }
+}
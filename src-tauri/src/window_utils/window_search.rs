@@ -1,59 +1,83 @@
-use windows::core::BOOL;
-use windows::Win32::Foundation::{HWND, LPARAM};
-use windows::Win32::UI::WindowsAndMessaging::{
-    EnumWindows, GetWindowTextLengthW, GetWindowTextW, IsWindowVisible,
-};
-
-/// + 창 제목을 가져오는 콜백 함수
-unsafe extern "system" fn enum_windows_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
-    let titles = &mut *(lparam.0 as *mut Vec<String>);
-
-    // ? 창이 보이는 상태인지 확인
-    if IsWindowVisible(hwnd).as_bool() {
-        let length = GetWindowTextLengthW(hwnd) + 1;
-        let mut buffer = vec![0u16; length as usize];
-
-        // ? 창 제목 가져오기
-        if GetWindowTextW(hwnd, &mut buffer) > 0 {
-            let title = String::from_utf16_lossy(&buffer[..buffer.len() - 1]);
-            if !title.is_empty() {
-                titles.push(title);
-            }
-        }
-    }
-
-    BOOL(1) // ? 계속 탐색
-}
-
-/// + 모든 창 제목을 가져오는 함수
+use super::title_match::TitleMatcher;
+use super::window_system::{window_system, WindowId, WindowSystem};
+use crate::state::types::{FocusSettings, TitleMatchMode};
+use serde::Serialize;
+
+/// * 조건에 맞는 창 정보 구조체
+#[derive(Serialize, Clone, Debug)]
+pub struct WindowMatch {
+    pub id: WindowId,
+    pub title: String,
+    pub process: Option<String>, // 실행 파일 이름 (Process 모드일 때만 조회)
+}
+
+/// + 모든 창 제목을 가져오는 함수 (보이는 창만)
 pub fn get_all_window_titles() -> Vec<String> {
-    let mut titles: Vec<String> = Vec::new();
+    window_system()
+        .enumerate_windows()
+        .into_iter()
+        .filter(|window| window.visible)
+        .map(|window| window.title)
+        .collect()
+}
+
+/// + 조건에 맞는 창 목록 (보이는 창만)
+pub fn find_matching_windows(matcher: &TitleMatcher) -> Vec<WindowMatch> {
+    matching_windows(window_system(), matcher)
+}
 
-    unsafe {
-        match EnumWindows(
-            Some(enum_windows_proc),
-            LPARAM(&mut titles as *mut _ as isize),
-        ) {
-            Ok(_) => { /* 성공적으로 실행됨 */ }
-            Err(e) => eprintln!("EnumWindows 호출 실패: {:?}", e),
-        }
-    }
+/// + 지정한 창 제어 구현에서 조건에 맞는 창 목록
+pub fn matching_windows(system: &dyn WindowSystem, matcher: &TitleMatcher) -> Vec<WindowMatch> {
+    system
+        .enumerate_windows()
+        .into_iter()
+        .filter(|window| window.visible)
+        .filter_map(|window| {
+            // ? 프로세스 이름 조회는 비용이 있어서 필요할 때만 실행
+            let process = match (matcher.needs_process(), window.pid) {
+                (true, Some(pid)) => system.process_name(pid),
+                _ => None,
+            };
 
-    titles
+            matcher
+                .matches(&window.title, process.as_deref())
+                .then_some(WindowMatch {
+                    id: window.id,
+                    title: window.title,
+                    process,
+                })
+        })
+        .collect()
+}
+
+/// + Auto Focus 설정으로 게임 창 찾기
+pub fn find_game_window(settings: &FocusSettings) -> Result<Option<WindowMatch>, String> {
+    let matcher = TitleMatcher::new(settings.match_mode, &settings.game_title)?;
+    Ok(find_matching_windows(&matcher).into_iter().next())
 }
 
 /// + 특정 제목을 가진 창 찾기 (Tauri Command)
+// ? mode를 지정하지 않으면 제목에 포함되는지로 비교
 #[tauri::command]
-pub fn find_window_by_title(target: String) -> Option<String> {
-    let titles = get_all_window_titles();
-
-    for title in titles {
-        if title.contains(&target) {
-            return Some(title);
-        }
-    }
+pub fn find_window_by_title(
+    target: String,
+    mode: Option<TitleMatchMode>,
+) -> Result<Option<String>, String> {
+    let matcher = TitleMatcher::new(mode.unwrap_or(TitleMatchMode::Contains), &target)?;
+    Ok(find_matching_windows(&matcher)
+        .into_iter()
+        .next()
+        .map(|window| window.title))
+}
 
-    None
+/// + 설정한 비교 방식으로 현재 일치하는 창 미리보기 (Tauri Command)
+#[tauri::command]
+pub fn preview_title_matches(
+    pattern: String,
+    mode: TitleMatchMode,
+) -> Result<Vec<WindowMatch>, String> {
+    let matcher = TitleMatcher::new(mode, &pattern)?;
+    Ok(find_matching_windows(&matcher))
 }
 
 /// + 모든 창 제목을 가져오는 함수 (Tauri Command)
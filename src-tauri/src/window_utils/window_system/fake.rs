@@ -0,0 +1,327 @@
+use super::{
+    EventCallback, EventSubscription, Rect, SystemEvent, WindowId, WindowInfo, WindowSystem,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// > 메모리 창 제어 (테스트, 미지원 플랫폼용)
+// ? 실제 창 대신 등록된 창 목록으로 동작
+
+/// * 가짜 창 구조체
+#[derive(Clone, Debug)]
+struct FakeWindow {
+    info: WindowInfo,
+    minimized: bool,
+    client_rect: Rect, // 내용 영역 (화면 좌표)
+}
+
+#[derive(Default)]
+struct FakeState {
+    windows: Vec<FakeWindow>,
+    foreground: Option<WindowId>,
+    #[cfg(test)]
+    focus_requests: Vec<WindowId>, // focus_window 호출 기록
+    processes: HashMap<u32, String>, // pid별 실행 파일 이름
+    work_areas: Vec<Rect>,           // 모니터별 작업 영역
+}
+
+/// * 메모리 창 제어 구조체
+#[derive(Default)]
+pub struct FakeWindowSystem {
+    state: Mutex<FakeState>,
+    listener: Arc<Mutex<Option<EventCallback>>>, // subscribe로 등록된 콜백
+}
+
+impl FakeWindowSystem {
+    /// + 구독 중인 콜백에 이벤트 전달 (마우스 이동 등 OS 이벤트 흉내)
+    pub fn emit(&self, event: SystemEvent) {
+        if let Some(callback) = self.listener.lock().unwrap().as_ref() {
+            callback(event);
+        }
+    }
+}
+
+// ? 창 목록을 바꾸는 함수는 테스트에서만 사용 (미지원 플랫폼은 빈 목록)
+#[cfg(test)]
+impl FakeWindowSystem {
+    /// + 창 추가 (같은 id가 있으면 교체)
+    pub fn add_window(&self, id: WindowId, title: &str, pid: Option<u32>) {
+        let mut state = self.state.lock().unwrap();
+        state.windows.retain(|w| w.info.id != id);
+        state.windows.push(FakeWindow {
+            info: WindowInfo {
+                id,
+                title: title.to_string(),
+                pid,
+                visible: true,
+            },
+            minimized: false,
+            client_rect: Rect::default(),
+        });
+    }
+
+    /// + 창 제거 (포커스된 창이면 포커스도 해제)
+    pub fn remove_window(&self, id: WindowId) {
+        let mut state = self.state.lock().unwrap();
+        state.windows.retain(|w| w.info.id != id);
+        if state.foreground == Some(id) {
+            state.foreground = None;
+        }
+    }
+
+    pub fn set_title(&self, id: WindowId, title: &str) {
+        self.update(id, |w| w.info.title = title.to_string());
+    }
+
+    pub fn set_visible(&self, id: WindowId, visible: bool) {
+        self.update(id, |w| w.info.visible = visible);
+    }
+
+    pub fn set_minimized(&self, id: WindowId, minimized: bool) {
+        self.update(id, |w| w.minimized = minimized);
+    }
+
+    /// + 창 내용 영역 지정 (창 이동, 크기 변경 흉내)
+    pub fn set_client_rect(&self, id: WindowId, rect: Rect) {
+        self.update(id, |w| w.client_rect = rect);
+    }
+
+    /// + 프로세스 실행 파일 이름 지정 (None이면 프로세스 종료)
+    pub fn set_process_name(&self, pid: u32, name: Option<&str>) {
+        let mut state = self.state.lock().unwrap();
+        match name {
+            Some(name) => state.processes.insert(pid, name.to_string()),
+            None => state.processes.remove(&pid),
+        };
+    }
+
+    /// + 작업 영역 추가 (겹치는 모니터의 작업 영역으로 사용)
+    pub fn add_work_area(&self, area: Rect) {
+        self.state.lock().unwrap().work_areas.push(area);
+    }
+
+    /// + 포커스된 창 지정 (사용자가 직접 창을 선택한 경우)
+    pub fn set_foreground(&self, id: Option<WindowId>) {
+        self.state.lock().unwrap().foreground = id;
+        self.emit(SystemEvent::ForegroundChanged);
+    }
+
+    /// + focus_window로 요청된 창 id 목록
+    pub fn focus_requests(&self) -> Vec<WindowId> {
+        self.state.lock().unwrap().focus_requests.clone()
+    }
+
+    fn update(&self, id: WindowId, f: impl FnOnce(&mut FakeWindow)) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(window) = state.windows.iter_mut().find(|w| w.info.id == id) {
+            f(window);
+        }
+    }
+}
+
+impl WindowSystem for FakeWindowSystem {
+    fn enumerate_windows(&self) -> Vec<WindowInfo> {
+        let state = self.state.lock().unwrap();
+        state.windows.iter().map(|w| w.info.clone()).collect()
+    }
+
+    fn foreground_window(&self) -> Option<WindowInfo> {
+        let state = self.state.lock().unwrap();
+        let id = state.foreground?;
+        state
+            .windows
+            .iter()
+            .find(|w| w.info.id == id)
+            .map(|w| w.info.clone())
+    }
+
+    fn focus_window(&self, id: WindowId) -> bool {
+        let mut state = self.state.lock().unwrap();
+        #[cfg(test)]
+        state.focus_requests.push(id);
+
+        // ? 숨겨진 창은 포커스할 수 없고, 최소화된 창은 복원되면서 포커스됨
+        let Some(window) = state
+            .windows
+            .iter_mut()
+            .find(|w| w.info.id == id && w.info.visible)
+        else {
+            return false;
+        };
+        window.minimized = false;
+        state.foreground = Some(id);
+        drop(state);
+
+        self.emit(SystemEvent::ForegroundChanged);
+        true
+    }
+
+    fn is_minimized(&self, id: WindowId) -> bool {
+        let state = self.state.lock().unwrap();
+        state.windows.iter().any(|w| w.info.id == id && w.minimized)
+    }
+
+    fn process_name(&self, pid: u32) -> Option<String> {
+        self.state.lock().unwrap().processes.get(&pid).cloned()
+    }
+
+    fn work_area(&self, monitor: &Rect) -> Option<Rect> {
+        let state = self.state.lock().unwrap();
+        state
+            .work_areas
+            .iter()
+            .find_map(|area| area.intersection(monitor))
+    }
+
+    fn client_rect(&self, id: WindowId) -> Option<Rect> {
+        let state = self.state.lock().unwrap();
+        state
+            .windows
+            .iter()
+            .find(|w| w.info.id == id)
+            .map(|w| w.client_rect)
+    }
+
+    fn subscribe(&self, callback: EventCallback) -> Option<EventSubscription> {
+        *self.listener.lock().unwrap() = Some(callback);
+
+        let listener = Arc::clone(&self.listener);
+        Some(EventSubscription::new(move || {
+            *listener.lock().unwrap() = None;
+        }))
+    }
+}
+
+// > 메모리 창 제어 (테스트, 미지원 플랫폼용)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::types::TitleMatchMode;
+    use crate::window_utils::title_match::TitleMatcher;
+    use crate::window_utils::window_search::matching_windows;
+
+    fn system() -> FakeWindowSystem {
+        let system = FakeWindowSystem::default();
+        system.add_window(1, "LOST ARK (64-bit, DX11) v.3.5.7.1", Some(100));
+        system.add_window(2, "메모장", Some(200));
+        system.add_window(3, "LOST ARK 공략 - Chrome", Some(300));
+        system.set_process_name(100, Some("LOSTARK.exe"));
+        system.set_process_name(300, Some("chrome.exe"));
+        system
+    }
+
+    fn matched_ids(system: &FakeWindowSystem, mode: TitleMatchMode, pattern: &str) -> Vec<u64> {
+        let matcher = TitleMatcher::new(mode, pattern).unwrap();
+        matching_windows(system, &matcher)
+            .into_iter()
+            .map(|window| window.id)
+            .collect()
+    }
+
+    #[test]
+    fn enumerate_reflects_added_removed_and_renamed_windows() {
+        let system = system();
+        system.remove_window(2);
+        system.set_title(3, "새 탭");
+
+        let windows = system.enumerate_windows();
+        let titles: Vec<_> = windows.iter().map(|w| w.title.as_str()).collect();
+        assert_eq!(titles, vec!["LOST ARK (64-bit, DX11) v.3.5.7.1", "새 탭"]);
+        assert_eq!(system.find_window("새 탭").map(|w| w.id), Some(3));
+    }
+
+    #[test]
+    fn title_matching_by_mode() {
+        let system = system();
+
+        assert_eq!(
+            matched_ids(
+                &system,
+                TitleMatchMode::Exact,
+                "LOST ARK (64-bit, DX11) v.3.5.7.1"
+            ),
+            vec![1]
+        );
+        assert_eq!(
+            matched_ids(&system, TitleMatchMode::Prefix, "LOST ARK"),
+            vec![1, 3]
+        );
+        assert_eq!(
+            matched_ids(&system, TitleMatchMode::Contains, "공략"),
+            vec![3]
+        );
+        assert_eq!(
+            matched_ids(&system, TitleMatchMode::Regex, r"^LOST ARK \("),
+            vec![1]
+        );
+        assert_eq!(
+            matched_ids(&system, TitleMatchMode::Process, "lostark.exe"),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn hidden_windows_are_not_matched() {
+        let system = system();
+        system.set_visible(1, false);
+
+        assert_eq!(
+            matched_ids(&system, TitleMatchMode::Prefix, "LOST ARK"),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn focus_restores_minimized_and_rejects_hidden() {
+        let system = system();
+        system.set_minimized(1, true);
+        system.set_visible(2, false);
+
+        assert!(system.focus_window(1));
+        assert!(!system.is_minimized(1));
+        assert_eq!(system.foreground_window().map(|w| w.id), Some(1));
+
+        assert!(!system.focus_window(2));
+        assert_eq!(system.focus_requests(), vec![1, 2]);
+    }
+
+    #[test]
+    fn events_are_delivered_until_unsubscribed() {
+        let system = system();
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = Arc::clone(&received);
+        let subscription = system
+            .subscribe(Box::new(move |event| sink.lock().unwrap().push(event)))
+            .unwrap();
+
+        system.set_foreground(Some(2));
+        system.emit(SystemEvent::MouseMoved { x: 10, y: 20 });
+        drop(subscription);
+        system.set_foreground(Some(1));
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                SystemEvent::ForegroundChanged,
+                SystemEvent::MouseMoved { x: 10, y: 20 }
+            ]
+        );
+    }
+
+    #[test]
+    fn client_rect_and_work_area() {
+        let system = system();
+        system.set_client_rect(1, Rect::new(100, 100, 1280, 720));
+        system.add_work_area(Rect::new(0, 0, 1920, 1040));
+
+        assert_eq!(system.client_rect(1), Some(Rect::new(100, 100, 1280, 720)));
+        assert_eq!(system.client_rect(9), None);
+        assert_eq!(
+            system.work_area(&Rect::new(0, 0, 1920, 1080)),
+            Some(Rect::new(0, 0, 1920, 1040))
+        );
+        assert_eq!(system.work_area(&Rect::new(1920, 0, 1920, 1080)), None);
+    }
+}
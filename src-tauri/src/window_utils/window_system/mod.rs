@@ -0,0 +1,192 @@
+// ? Windows는 테스트에서만 사용
+#[cfg(any(test, not(windows)))]
+pub mod fake;
+#[cfg(windows)]
+mod win32;
+#[cfg(target_os = "linux")]
+mod x11;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+// > 플랫폼 창 제어
+// ? 포커스 로직이 Win32 API를 직접 호출하지 않도록 창 제어를 trait으로 분리
+// ? Windows: Win32, Linux: X11/EWMH, 그 외(또는 X11 연결 실패): 빈 FakeWindowSystem
+// ? 이벤트 구독은 Win32 훅만 지원 (X11은 subscribe 미지원으로 주기 확인)
+
+/// ? 플랫폼별 창 식별자 (HWND, X11 Window)
+pub type WindowId = u64;
+
+/// * 최상위 창 정보 구조체
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct WindowInfo {
+    pub id: WindowId,
+    pub title: String,
+    pub pid: Option<u32>, // 창을 소유한 프로세스 ID
+    pub visible: bool,
+}
+
+/// * 화면 사각형 (물리 좌표, 보조 모니터는 음수 좌표 가능)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> i32 {
+        self.x + self.width as i32
+    }
+
+    pub fn bottom(&self) -> i32 {
+        self.y + self.height as i32
+    }
+
+    pub fn center(&self) -> (i32, i32) {
+        (
+            self.x + self.width as i32 / 2,
+            self.y + self.height as i32 / 2,
+        )
+    }
+
+    /// + 점이 사각형 안에 있는지 확인 (오른쪽, 아래쪽 경계 포함)
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x <= self.right() && y >= self.y && y <= self.bottom()
+    }
+
+    /// + 두 사각형이 겹치는 영역 (겹치지 않으면 None)
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        (right > x && bottom > y).then(|| Rect::new(x, y, (right - x) as u32, (bottom - y) as u32))
+    }
+
+    /// + 겹치는 영역 넓이
+    pub fn overlap_area(&self, other: &Rect) -> u64 {
+        self.intersection(other)
+            .map_or(0, |rect| rect.width as u64 * rect.height as u64)
+    }
+}
+
+/// * 창 시스템 이벤트
+// ? X11은 이벤트 구독을 지원하지 않아서 Windows, 테스트에서만 생성됨
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(any(windows, test)), allow(dead_code))]
+pub enum SystemEvent {
+    ForegroundChanged,             // 포커스된 창 변경
+    MouseMoved { x: i32, y: i32 }, // 마우스 이동 (물리 화면 좌표)
+}
+
+/// ? 이벤트 수신 콜백 (OS 훅 스레드에서 호출되므로 가볍게 처리해야 함)
+pub type EventCallback = Box<dyn Fn(SystemEvent) + Send + Sync>;
+
+/// ? 디스플레이 구성 변경 콜백 (OS 메시지 스레드에서 호출되므로 가볍게 처리해야 함)
+pub type DisplayCallback = Box<dyn Fn() + Send + Sync>;
+
+/// * 이벤트 구독 핸들 (drop 시 구독 해제)
+pub struct EventSubscription {
+    unsubscribe: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl EventSubscription {
+    pub fn new(unsubscribe: impl FnOnce() + Send + 'static) -> Self {
+        Self {
+            unsubscribe: Some(Box::new(unsubscribe)),
+        }
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+/// * 창 제어 trait
+pub trait WindowSystem: Send + Sync {
+    /// + 제목이 있는 최상위 창 목록 (숨겨진 창 포함)
+    fn enumerate_windows(&self) -> Vec<WindowInfo>;
+
+    /// + 현재 포커스된 창
+    fn foreground_window(&self) -> Option<WindowInfo>;
+
+    /// + 창으로 포커스 이동 (성공 여부 반환)
+    fn focus_window(&self, id: WindowId) -> bool;
+
+    /// + 창이 최소화 상태인지 확인
+    fn is_minimized(&self, id: WindowId) -> bool;
+
+    /// + 프로세스 실행 파일 이름 (예: LOSTARK.exe, 확인할 수 없으면 None)
+    fn process_name(&self, pid: u32) -> Option<String>;
+
+    /// + 모니터에서 작업 표시줄 등을 제외한 작업 영역 (확인할 수 없으면 None)
+    fn work_area(&self, monitor: &Rect) -> Option<Rect>;
+
+    /// + 창 테두리, 제목 표시줄을 제외한 내용 영역 (화면 좌표, 창이 없으면 None)
+    fn client_rect(&self, id: WindowId) -> Option<Rect>;
+
+    /// + 제목이 정확히 일치하는 창 찾기
+    fn find_window(&self, title: &str) -> Option<WindowInfo> {
+        self.enumerate_windows()
+            .into_iter()
+            .find(|window| window.title == title)
+    }
+
+    /// + 포커스 변경, 마우스 이동 이벤트 구독
+    // ? 지원하지 않는 플랫폼은 None (호출하는 쪽에서 주기적으로 확인)
+    fn subscribe(&self, _callback: EventCallback) -> Option<EventSubscription> {
+        None
+    }
+
+    /// + 디스플레이 구성 변경 구독 (모니터 연결/해제, 해상도, 작업 표시줄 위치)
+    // ? 지원하지 않는 플랫폼은 None (호출하는 쪽에서 주기적으로 확인)
+    fn watch_display(&self, _callback: DisplayCallback) -> Option<EventSubscription> {
+        None
+    }
+}
+
+static WINDOW_SYSTEM: Lazy<Box<dyn WindowSystem>> = Lazy::new(platform_window_system);
+
+#[cfg(windows)]
+fn platform_window_system() -> Box<dyn WindowSystem> {
+    Box::new(win32::Win32WindowSystem)
+}
+
+#[cfg(target_os = "linux")]
+fn platform_window_system() -> Box<dyn WindowSystem> {
+    match x11::X11WindowSystem::connect() {
+        Some(system) => Box::new(system),
+        None => {
+            println!("⚠️ X11 디스플레이에 연결할 수 없어 창 제어 기능을 사용하지 않습니다.");
+            Box::new(fake::FakeWindowSystem::default())
+        }
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn platform_window_system() -> Box<dyn WindowSystem> {
+    Box::new(fake::FakeWindowSystem::default())
+}
+
+/// + 현재 플랫폼의 창 제어 구현
+pub fn window_system() -> &'static dyn WindowSystem {
+    WINDOW_SYSTEM.as_ref()
+}
+
+// > 플랫폼 창 제어
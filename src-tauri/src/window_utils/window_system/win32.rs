@@ -0,0 +1,402 @@
+use super::{
+    DisplayCallback, EventCallback, EventSubscription, Rect, SystemEvent, WindowId, WindowInfo,
+    WindowSystem,
+};
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use windows::core::{w, BOOL, PWSTR};
+use windows::Win32::Foundation::{
+    CloseHandle, HINSTANCE, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM,
+};
+use windows::Win32::Graphics::Gdi::{
+    ClientToScreen, GetMonitorInfoW, MonitorFromRect, MONITORINFO, MONITOR_DEFAULTTONULL,
+};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::System::Threading::{
+    GetCurrentThreadId, OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+    PROCESS_QUERY_LIMITED_INFORMATION,
+};
+use windows::Win32::UI::Accessibility::{SetWinEventHook, UnhookWinEvent, HWINEVENTHOOK};
+use windows::Win32::UI::WindowsAndMessaging::{
+    CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, EnumWindows, GetClientRect,
+    GetCursorPos, GetForegroundWindow, GetMessageW, GetWindowTextLengthW, GetWindowTextW,
+    GetWindowThreadProcessId, IsIconic, IsWindow, IsWindowVisible, PostThreadMessageW,
+    RegisterClassW, SetForegroundWindow, EVENT_OBJECT_LOCATIONCHANGE, EVENT_SYSTEM_FOREGROUND, MSG,
+    OBJID_CURSOR, SPI_SETWORKAREA, WINDOW_EX_STYLE, WINDOW_STYLE, WINEVENT_OUTOFCONTEXT,
+    WM_DISPLAYCHANGE, WM_QUIT, WM_SETTINGCHANGE, WNDCLASSW,
+};
+
+// > Win32 창 제어
+// ? 이벤트 구독: SetWinEventHook(포커스 변경, 커서 위치 변경)
+// ? 전역 저수준 마우스 훅(WH_MOUSE_LL)은 모든 마우스 입력을 지연시킬 수 있어서 사용하지 않고
+// ? 커서 이동 알림(OBJID_CURSOR)을 비동기로 받아 창 영역과 비교 (hit-test는 추적 스레드에서)
+// ? 훅 콜백은 훅을 설치한 스레드의 메시지 루프에서 호출되므로 구독마다 전용 스레드 사용
+// ? 디스플레이 변경: 보이지 않는 최상위 창으로 WM_DISPLAYCHANGE, WM_SETTINGCHANGE(작업 영역) 수신
+
+/// ? 현재 구독 (id, 콜백) - 훅 콜백은 전역 함수라서 static으로 전달
+static LISTENER: Mutex<Option<(u64, EventCallback)>> = Mutex::new(None);
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+/// ? 디스플레이 변경 구독 (id, 콜백) - 창 프로시저도 전역 함수라서 static으로 전달
+static DISPLAY_LISTENER: Mutex<Option<(u64, DisplayCallback)>> = Mutex::new(None);
+
+pub struct Win32WindowSystem;
+
+fn to_hwnd(id: WindowId) -> HWND {
+    HWND(id as usize as *mut c_void)
+}
+
+fn to_id(hwnd: HWND) -> WindowId {
+    hwnd.0 as usize as WindowId
+}
+
+/// + 창 제목 가져오기
+unsafe fn window_title(hwnd: HWND) -> String {
+    let length = GetWindowTextLengthW(hwnd);
+    if length <= 0 {
+        return String::new();
+    }
+
+    let mut buffer = vec![0u16; length as usize + 1];
+    let copied = GetWindowTextW(hwnd, &mut buffer);
+    String::from_utf16_lossy(&buffer[..copied.max(0) as usize])
+}
+
+/// + 창 정보 가져오기 (제목이 없는 창은 None)
+unsafe fn window_info(hwnd: HWND) -> Option<WindowInfo> {
+    let title = window_title(hwnd);
+    if title.is_empty() {
+        return None;
+    }
+
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+
+    Some(WindowInfo {
+        id: to_id(hwnd),
+        title,
+        pid: (pid != 0).then_some(pid),
+        visible: IsWindowVisible(hwnd).as_bool(),
+    })
+}
+
+/// + 창 핸들을 모으는 콜백 함수
+unsafe extern "system" fn enum_windows_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let handles = &mut *(lparam.0 as *mut Vec<HWND>);
+    handles.push(hwnd);
+
+    BOOL(1) // ? 계속 탐색
+}
+
+/// + 구독 중인 콜백에 이벤트 전달
+fn emit(event: SystemEvent) {
+    if let Some((_, callback)) = LISTENER.lock().unwrap().as_ref() {
+        callback(event);
+    }
+}
+
+/// + 포커스 변경, 커서 이동 훅 콜백
+// ? WINEVENT_OUTOFCONTEXT라서 입력 처리를 막지 않음 (메시지 루프에서 비동기 호출)
+unsafe extern "system" fn win_event_proc(
+    _hook: HWINEVENTHOOK,
+    event: u32,
+    hwnd: HWND,
+    id_object: i32,
+    _id_child: i32,
+    _event_thread: u32,
+    _event_time: u32,
+) {
+    match event {
+        EVENT_SYSTEM_FOREGROUND => emit(SystemEvent::ForegroundChanged),
+        // ? 커서 이외의 위치 변경(창 이동, 캐럿 등)은 무시
+        EVENT_OBJECT_LOCATIONCHANGE if hwnd.0.is_null() && id_object == OBJID_CURSOR.0 => {
+            let mut point = POINT::default();
+            if GetCursorPos(&mut point).is_ok() {
+                emit(SystemEvent::MouseMoved {
+                    x: point.x,
+                    y: point.y,
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// + 메시지 루프에서 다음 메시지 받기 (WM_QUIT이거나 오류면 false)
+// ? GetMessageW는 오류 시 -1을 반환하므로 as_bool()로 확인하면 오류가 반복되며 멈추지 않음
+unsafe fn next_message(msg: &mut MSG) -> bool {
+    match GetMessageW(msg, None, 0, 0).0 {
+        -1 => {
+            eprintln!("❌ 메시지 루프 오류: {}", std::io::Error::last_os_error());
+            false
+        }
+        0 => false,
+        _ => true,
+    }
+}
+
+/// + 훅 설치 후 메시지 루프 실행 (WM_QUIT 수신 시 훅 해제)
+// ? 설치 결과는 `ready`로 전달 (성공 시 스레드 id)
+unsafe fn run_hook_thread(ready: mpsc::Sender<Option<u32>>) {
+    let hook = |event: u32| {
+        SetWinEventHook(
+            event,
+            event,
+            None,
+            Some(win_event_proc),
+            0,
+            0,
+            WINEVENT_OUTOFCONTEXT,
+        )
+    };
+    let hooks = [
+        hook(EVENT_SYSTEM_FOREGROUND),
+        hook(EVENT_OBJECT_LOCATIONCHANGE),
+    ];
+
+    let installed = hooks.iter().all(|hook| !hook.is_invalid());
+    let _ = ready.send(installed.then(|| GetCurrentThreadId()));
+
+    if installed {
+        let mut msg = MSG::default();
+        // ? 훅 콜백은 GetMessageW 안에서 호출됨
+        while next_message(&mut msg) {}
+    } else {
+        eprintln!("❌ 창 이벤트 훅 설치 실패");
+    }
+
+    for hook in hooks.into_iter().filter(|hook| !hook.is_invalid()) {
+        let _ = UnhookWinEvent(hook);
+    }
+}
+
+unsafe extern "system" fn display_window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    // ? 작업 표시줄 위치, 크기 변경은 SPI_SETWORKAREA 설정 변경으로 전달됨
+    let changed = msg == WM_DISPLAYCHANGE
+        || (msg == WM_SETTINGCHANGE && wparam.0 == SPI_SETWORKAREA.0 as usize);
+
+    if changed {
+        if let Some((_, callback)) = DISPLAY_LISTENER.lock().unwrap().as_ref() {
+            callback();
+        }
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+/// + 디스플레이 변경 수신 스레드 (메시지 루프가 끝나면 창 제거)
+unsafe fn run_display_thread(ready: mpsc::Sender<Option<u32>>) {
+    let instance: HINSTANCE = GetModuleHandleW(None).unwrap_or_default().into();
+    let class_name = w!("LoaAssistDisplayWatcher");
+
+    let class = WNDCLASSW {
+        lpfnWndProc: Some(display_window_proc),
+        hInstance: instance,
+        lpszClassName: class_name,
+        ..Default::default()
+    };
+    // ? 다시 구독할 때는 이미 등록된 클래스라서 실패하지만 창 생성에는 문제 없음
+    RegisterClassW(&class);
+
+    // ? 메시지 전용 창(HWND_MESSAGE)은 브로드캐스트 메시지를 받지 못하므로 표시하지 않는 최상위 창 사용
+    let hwnd = CreateWindowExW(
+        WINDOW_EX_STYLE::default(),
+        class_name,
+        w!(""),
+        WINDOW_STYLE::default(),
+        0,
+        0,
+        0,
+        0,
+        None,
+        None,
+        Some(instance),
+        None,
+    );
+
+    let Ok(hwnd) = hwnd else {
+        eprintln!("❌ 디스플레이 변경 감시 창 생성 실패");
+        let _ = ready.send(None);
+        return;
+    };
+    let _ = ready.send(Some(GetCurrentThreadId()));
+
+    let mut msg = MSG::default();
+    while next_message(&mut msg) {
+        DispatchMessageW(&msg);
+    }
+    let _ = DestroyWindow(hwnd);
+}
+
+impl WindowSystem for Win32WindowSystem {
+    fn enumerate_windows(&self) -> Vec<WindowInfo> {
+        let mut handles: Vec<HWND> = Vec::new();
+
+        unsafe {
+            if let Err(e) = EnumWindows(
+                Some(enum_windows_proc),
+                LPARAM(&mut handles as *mut _ as isize),
+            ) {
+                eprintln!("EnumWindows 호출 실패: {:?}", e);
+            }
+
+            handles.into_iter().filter_map(|h| window_info(h)).collect()
+        }
+    }
+
+    fn foreground_window(&self) -> Option<WindowInfo> {
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            if hwnd.0.is_null() {
+                return None; // ? 포커스된 창이 없음
+            }
+            window_info(hwnd)
+        }
+    }
+
+    fn focus_window(&self, id: WindowId) -> bool {
+        unsafe { SetForegroundWindow(to_hwnd(id)).as_bool() }
+    }
+
+    fn is_minimized(&self, id: WindowId) -> bool {
+        let hwnd = to_hwnd(id);
+        unsafe { IsWindow(Some(hwnd)).as_bool() && IsIconic(hwnd).as_bool() }
+    }
+
+    fn process_name(&self, pid: u32) -> Option<String> {
+        unsafe {
+            // ? 관리자 권한 프로세스도 LIMITED 권한으로는 경로 조회 가능
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+
+            let mut buffer = [0u16; 1024];
+            let mut length = buffer.len() as u32;
+            let result = QueryFullProcessImageNameW(
+                process,
+                PROCESS_NAME_WIN32,
+                PWSTR(buffer.as_mut_ptr()),
+                &mut length,
+            );
+            let _ = CloseHandle(process);
+            result.ok()?;
+
+            let path = String::from_utf16_lossy(&buffer[..length as usize]);
+            path.rsplit('\\').next().map(str::to_string)
+        }
+    }
+
+    fn work_area(&self, monitor: &Rect) -> Option<Rect> {
+        let rect = RECT {
+            left: monitor.x,
+            top: monitor.y,
+            right: monitor.right(),
+            bottom: monitor.bottom(),
+        };
+
+        unsafe {
+            let handle = MonitorFromRect(&rect, MONITOR_DEFAULTTONULL);
+            if handle.is_invalid() {
+                return None;
+            }
+
+            let mut info = MONITORINFO {
+                cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+                ..Default::default()
+            };
+            if !GetMonitorInfoW(handle, &mut info).as_bool() {
+                return None;
+            }
+
+            let work = info.rcWork;
+            Some(Rect::new(
+                work.left,
+                work.top,
+                (work.right - work.left) as u32,
+                (work.bottom - work.top) as u32,
+            ))
+        }
+    }
+
+    fn client_rect(&self, id: WindowId) -> Option<Rect> {
+        let hwnd = to_hwnd(id);
+        unsafe {
+            if !IsWindow(Some(hwnd)).as_bool() {
+                return None;
+            }
+
+            // ? GetClientRect는 (0, 0) 기준 크기만 반환하므로 원점을 화면 좌표로 변환
+            let mut rect = RECT::default();
+            GetClientRect(hwnd, &mut rect).ok()?;
+            let mut origin = POINT {
+                x: rect.left,
+                y: rect.top,
+            };
+            if !ClientToScreen(hwnd, &mut origin).as_bool() {
+                return None;
+            }
+
+            Some(Rect::new(
+                origin.x,
+                origin.y,
+                (rect.right - rect.left) as u32,
+                (rect.bottom - rect.top) as u32,
+            ))
+        }
+    }
+
+    fn subscribe(&self, callback: EventCallback) -> Option<EventSubscription> {
+        let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::SeqCst);
+        *LISTENER.lock().unwrap() = Some((id, callback));
+
+        let (ready_tx, ready_rx) = mpsc::channel();
+        thread::spawn(move || unsafe { run_hook_thread(ready_tx) });
+
+        let Some(thread_id) = ready_rx.recv().ok().flatten() else {
+            LISTENER
+                .lock()
+                .unwrap()
+                .take_if(|(current, _)| *current == id);
+            return None;
+        };
+
+        Some(EventSubscription::new(move || {
+            // ? 훅 스레드 메시지 루프 종료 → 훅 해제
+            let _ = unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) };
+            // ? 그 사이 새 구독이 등록되었다면 유지
+            LISTENER
+                .lock()
+                .unwrap()
+                .take_if(|(current, _)| *current == id);
+        }))
+    }
+
+    fn watch_display(&self, callback: DisplayCallback) -> Option<EventSubscription> {
+        let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::SeqCst);
+        *DISPLAY_LISTENER.lock().unwrap() = Some((id, callback));
+
+        let (ready_tx, ready_rx) = mpsc::channel();
+        thread::spawn(move || unsafe { run_display_thread(ready_tx) });
+
+        let Some(thread_id) = ready_rx.recv().ok().flatten() else {
+            DISPLAY_LISTENER
+                .lock()
+                .unwrap()
+                .take_if(|(current, _)| *current == id);
+            return None;
+        };
+
+        Some(EventSubscription::new(move || {
+            let _ = unsafe { PostThreadMessageW(thread_id, WM_QUIT, WPARAM(0), LPARAM(0)) };
+            DISPLAY_LISTENER
+                .lock()
+                .unwrap()
+                .take_if(|(current, _)| *current == id);
+        }))
+    }
+}
+
+// > Win32 창 제어
@@ -0,0 +1,276 @@
+use super::{Rect, WindowId, WindowInfo, WindowSystem};
+use std::{
+    ffi::CString,
+    mem,
+    os::raw::{c_int, c_long, c_uchar, c_ulong},
+    ptr, slice,
+    sync::Mutex,
+};
+use x11_dl::xlib::{self, Xlib};
+
+// > X11 (EWMH) 창 제어
+// ? libX11은 실행 시 동적으로 불러오므로 X11이 없는 환경에서도 빌드 가능
+// ? 창 목록/포커스는 창 관리자가 관리하는 EWMH 속성(_NET_CLIENT_LIST, _NET_ACTIVE_WINDOW)을 사용
+
+/// ? 속성을 읽을 최대 길이 (32bit 단위)
+const MAX_PROPERTY_LEN: c_long = 0xFFFF;
+
+/// * X11 연결 (Display 포인터는 Mutex 안에서만 사용)
+struct Connection {
+    display: *mut xlib::Display,
+    root: xlib::Window,
+}
+
+unsafe impl Send for Connection {}
+
+pub struct X11WindowSystem {
+    xlib: Xlib,
+    conn: Mutex<Connection>,
+}
+
+impl X11WindowSystem {
+    /// + 기본 디스플레이에 연결 (libX11이 없거나 연결 실패 시 None)
+    pub fn connect() -> Option<Self> {
+        let xlib = Xlib::open().ok()?;
+        let display = unsafe { (xlib.XOpenDisplay)(ptr::null()) };
+        if display.is_null() {
+            return None;
+        }
+        let root = unsafe { (xlib.XDefaultRootWindow)(display) };
+
+        Some(Self {
+            xlib,
+            conn: Mutex::new(Connection { display, root }),
+        })
+    }
+
+    fn atom(&self, conn: &Connection, name: &str) -> xlib::Atom {
+        let name = CString::new(name).expect("atom 이름");
+        unsafe { (self.xlib.XInternAtom)(conn.display, name.as_ptr(), xlib::False) }
+    }
+
+    /// + 창 속성 읽기 (`format`: 8 = 문자열, 32 = Window/Atom/CARDINAL 목록)
+    // ? Xlib은 32bit 속성도 c_ulong 배열로 반환
+    unsafe fn read_property<T: Copy>(
+        &self,
+        conn: &Connection,
+        window: xlib::Window,
+        name: &str,
+        kind: xlib::Atom,
+        format: c_int,
+    ) -> Vec<T> {
+        let property = self.atom(conn, name);
+        let mut actual_type: xlib::Atom = 0;
+        let mut actual_format: c_int = 0;
+        let mut nitems: c_ulong = 0;
+        let mut bytes_after: c_ulong = 0;
+        let mut data: *mut c_uchar = ptr::null_mut();
+
+        let status = (self.xlib.XGetWindowProperty)(
+            conn.display,
+            window,
+            property,
+            0,
+            MAX_PROPERTY_LEN,
+            xlib::False,
+            kind,
+            &mut actual_type,
+            &mut actual_format,
+            &mut nitems,
+            &mut bytes_after,
+            &mut data,
+        );
+        if status != xlib::Success as c_int || data.is_null() {
+            return Vec::new();
+        }
+
+        let values = if actual_format == format {
+            slice::from_raw_parts(data as *const T, nitems as usize).to_vec()
+        } else {
+            Vec::new()
+        };
+        (self.xlib.XFree)(data as *mut _);
+        values
+    }
+
+    /// + 창 제목 (_NET_WM_NAME, 없으면 WM_NAME)
+    unsafe fn window_title(&self, conn: &Connection, window: xlib::Window) -> String {
+        let utf8 = self.atom(conn, "UTF8_STRING");
+        let mut bytes: Vec<u8> = self.read_property(conn, window, "_NET_WM_NAME", utf8, 8);
+        if bytes.is_empty() {
+            bytes = self.read_property(conn, window, "WM_NAME", xlib::XA_STRING, 8);
+        }
+        String::from_utf8_lossy(&bytes)
+            .trim_end_matches('\0')
+            .to_string()
+    }
+
+    /// + 창 상태에 특정 EWMH 상태가 포함되어 있는지 확인
+    unsafe fn has_state(&self, conn: &Connection, window: xlib::Window, state: &str) -> bool {
+        let target = self.atom(conn, state);
+        let states: Vec<c_ulong> =
+            self.read_property(conn, window, "_NET_WM_STATE", xlib::XA_ATOM, 32);
+        states.contains(&target)
+    }
+
+    unsafe fn window_info(&self, conn: &Connection, window: xlib::Window) -> Option<WindowInfo> {
+        let title = self.window_title(conn, window);
+        if title.is_empty() {
+            return None;
+        }
+
+        let pid: Vec<c_ulong> =
+            self.read_property(conn, window, "_NET_WM_PID", xlib::XA_CARDINAL, 32);
+
+        let mut attributes: xlib::XWindowAttributes = mem::zeroed();
+        let viewable = (self.xlib.XGetWindowAttributes)(conn.display, window, &mut attributes) != 0
+            && attributes.map_state == xlib::IsViewable;
+
+        Some(WindowInfo {
+            id: window as WindowId,
+            title,
+            pid: pid.first().map(|&pid| pid as u32),
+            visible: viewable && !self.has_state(conn, window, "_NET_WM_STATE_HIDDEN"),
+        })
+    }
+
+    /// + 최상위 창 목록 (_NET_CLIENT_LIST)
+    unsafe fn client_list(&self, conn: &Connection) -> Vec<xlib::Window> {
+        self.read_property(conn, conn.root, "_NET_CLIENT_LIST", xlib::XA_WINDOW, 32)
+    }
+}
+
+impl WindowSystem for X11WindowSystem {
+    fn enumerate_windows(&self) -> Vec<WindowInfo> {
+        let conn = self.conn.lock().unwrap();
+        unsafe {
+            self.client_list(&conn)
+                .into_iter()
+                .filter_map(|window| self.window_info(&conn, window))
+                .collect()
+        }
+    }
+
+    fn foreground_window(&self) -> Option<WindowInfo> {
+        let conn = self.conn.lock().unwrap();
+        unsafe {
+            let active: Vec<xlib::Window> =
+                self.read_property(&conn, conn.root, "_NET_ACTIVE_WINDOW", xlib::XA_WINDOW, 32);
+            match active.first() {
+                Some(&window) if window != 0 => self.window_info(&conn, window),
+                _ => None, // ? 포커스된 창이 없음
+            }
+        }
+    }
+
+    fn focus_window(&self, id: WindowId) -> bool {
+        let conn = self.conn.lock().unwrap();
+        let window = id as xlib::Window;
+
+        unsafe {
+            if !self.client_list(&conn).contains(&window) {
+                return false;
+            }
+
+            // ? EWMH: 루트 창에 _NET_ACTIVE_WINDOW 요청 (source indication 2 = 사용자 요청)
+            let mut data = xlib::ClientMessageData::new();
+            data.set_long(0, 2);
+            data.set_long(1, xlib::CurrentTime as c_long);
+
+            let mut event = xlib::XEvent {
+                client_message: xlib::XClientMessageEvent {
+                    type_: xlib::ClientMessage,
+                    serial: 0,
+                    send_event: xlib::True,
+                    display: conn.display,
+                    window,
+                    message_type: self.atom(&conn, "_NET_ACTIVE_WINDOW"),
+                    format: 32,
+                    data,
+                },
+            };
+
+            let sent = (self.xlib.XSendEvent)(
+                conn.display,
+                conn.root,
+                xlib::False,
+                xlib::SubstructureRedirectMask | xlib::SubstructureNotifyMask,
+                &mut event,
+            ) != 0;
+            (self.xlib.XFlush)(conn.display);
+            sent
+        }
+    }
+
+    fn is_minimized(&self, id: WindowId) -> bool {
+        let conn = self.conn.lock().unwrap();
+        unsafe { self.has_state(&conn, id as xlib::Window, "_NET_WM_STATE_HIDDEN") }
+    }
+
+    fn work_area(&self, monitor: &Rect) -> Option<Rect> {
+        let conn = self.conn.lock().unwrap();
+        unsafe {
+            // ? _NET_WORKAREA는 가상 화면 전체 기준 (데스크톱마다 x, y, width, height)
+            let desktop: Vec<c_ulong> = self.read_property(
+                &conn,
+                conn.root,
+                "_NET_CURRENT_DESKTOP",
+                xlib::XA_CARDINAL,
+                32,
+            );
+            let areas: Vec<c_ulong> =
+                self.read_property(&conn, conn.root, "_NET_WORKAREA", xlib::XA_CARDINAL, 32);
+
+            let index = desktop.first().copied().unwrap_or(0) as usize * 4;
+            let area = areas.get(index..index + 4)?;
+            let work = Rect::new(
+                area[0] as i32,
+                area[1] as i32,
+                area[2] as u32,
+                area[3] as u32,
+            );
+            work.intersection(monitor)
+        }
+    }
+
+    fn client_rect(&self, id: WindowId) -> Option<Rect> {
+        let conn = self.conn.lock().unwrap();
+        let window = id as xlib::Window;
+
+        unsafe {
+            // ? 닫힌 창에 요청하면 BadWindow 오류로 종료되므로 목록에 있는 창만 조회
+            if !self.client_list(&conn).contains(&window) {
+                return None;
+            }
+
+            let mut attributes: xlib::XWindowAttributes = mem::zeroed();
+            if (self.xlib.XGetWindowAttributes)(conn.display, window, &mut attributes) == 0 {
+                return None;
+            }
+
+            // ? 창 좌표는 부모(창 관리자 프레임) 기준이므로 루트 창 좌표로 변환
+            let (mut x, mut y): (c_int, c_int) = (0, 0);
+            let mut child: xlib::Window = 0;
+            let translated = (self.xlib.XTranslateCoordinates)(
+                conn.display,
+                window,
+                conn.root,
+                0,
+                0,
+                &mut x,
+                &mut y,
+                &mut child,
+            ) != 0;
+
+            translated.then(|| Rect::new(x, y, attributes.width as u32, attributes.height as u32))
+        }
+    }
+
+    fn process_name(&self, pid: u32) -> Option<String> {
+        // ? X11에는 프로세스 정보가 없으므로 /proc 사용
+        let exe = std::fs::read_link(format!("/proc/{}/exe", pid)).ok()?;
+        Some(exe.file_name()?.to_string_lossy().into_owned())
+    }
+}
+
+// > X11 (EWMH) 창 제어
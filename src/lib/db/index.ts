@@ -1,114 +1,59 @@
 import { BaseDirectory, exists } from "@tauri-apps/plugin-fs";
 import Database from "@tauri-apps/plugin-sql";
 
-import { CREATE_DEFAULT_TABLES, DEFAULT_RAIDS, DEFAULT_RAIDS_VERSION } from "./schema";
 import { liveDbName } from "../../stores/appStore";
 
-// + Database Initializer 작업
-export async function initializerDB() {
+import type { HomeworkType, LiveType, RaidType } from "$lib/types";
+import { invoke } from "$lib/utils/invoke";
+
+// + 이전 버전 SQLite(live.db) 숙제표를 Rust Backend로 가져오기 (1회)
+// ? 숙제표는 Rust Backend 상태(state::homework)에서 관리하며, SQLite는 더 이상 기록하지 않음
+export async function importLegacyHomework() {
+    if (await invoke("is_legacy_homework_imported")) return;
+
+    const legacy: HomeworkType = {
+        characters: [],
+        default_raids: [],
+        default_raids_version: 0,
+        group_raids: [],
+        live_raids: [],
+        legacy_imported: false
+    };
     let dbExists: boolean = false;
 
     try {
         dbExists = await exists(`${liveDbName}.db`, { baseDir: BaseDirectory.AppData });
-        // console.log(`Database file exists: ${dbExists}`);
     } catch (error) {
         console.error("Failed to check database file:", error);
+        return; // ! 다음 실행 때 다시 시도
     }
 
-    if (dbExists) return console.log("DB 초기화 작업 거부됨");
+    if (dbExists) {
+        console.log("이전 버전 숙제표 가져오기 시작");
+        const db = await Database.load(`sqlite:${liveDbName}.db`);
 
-    console.log("DB 초기화 작업 시작");
-    const db = await Database.load(`sqlite:${liveDbName}.db`);
+        try {
+            legacy.characters = await db.select("SELECT * FROM characters ORDER BY priority");
 
-    try {
-        await db.execute(CREATE_DEFAULT_TABLES);
-
-        await db.execute("BEGIN TRANSACTION;");
-
-        let index: number = 1;
-        for (const raid of DEFAULT_RAIDS) {
-            await db.execute(
-                `
-                INSERT OR IGNORE INTO default_raids
-                (raidId, priority, level, raidName, difficulty, gate, reward, resetType)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8);
-                `,
-                [
-                    raid.raidId,
-                    index,
-                    raid.level,
-                    raid.raidName,
-                    raid.difficulty,
-                    raid.gate,
-                    raid.reward,
-                    raid.resetType,
-                    0
-                ]
+            const groupRaids: RaidType[] = await db.select("SELECT * FROM group_raids ORDER BY priority");
+            const groupRaidsMap: Array<{ groupId: number; raidId: number }> = await db.select(
+                "SELECT * FROM group_raids_map"
             );
-            index++;
+            legacy.group_raids = groupRaids.map((group) => ({
+                ...group,
+                raidIds: groupRaidsMap.filter((map) => map.groupId === group.raidId).map((map) => map.raidId)
+            }));
+
+            const liveRaids: LiveType[] = await db.select("SELECT * FROM live_raids ORDER BY id");
+            legacy.live_raids = liveRaids.map((raid) => ({ ...raid, complete: Number(raid.complete) === 1 }));
+        } catch (error) {
+            console.error("Transaction failed:", error);
+            return; // ! 다음 실행 때 다시 시도
+        } finally {
+            await db.close();
         }
-
-        await db.execute("COMMIT;");
-
-        console.log(
-            "%cTransaction committed successfully.",
-            "color:white; font-style:bold; background-color:green; padding:3px; border-radius:4px; font-size:12px;"
-        );
-    } catch (error) {
-        await db.execute("ROLLBACK;"); // ! 오류가 발생하면 롤백
-        console.error("Transaction failed and rolled back:", error);
-    } finally {
-        await db.close();
     }
-}
-
-// + Default Raids Table 버전 업데이트 작업
-export async function updateDefaultRaidsTable(db: Database) {
-    console.log("Default Raids Table Update...");
-
-    try {
-        await db.execute("BEGIN TRANSACTION;");
-
-        await db.execute("DELETE FROM default_raids");
-
-        let index: number = 1;
-        for (const raid of DEFAULT_RAIDS) {
-            await db.execute(
-                `
-                INSERT OR IGNORE INTO default_raids
-                (raidId, priority, level, raidName, difficulty, gate, reward, resetType)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8);
-                `,
-                [
-                    raid.raidId,
-                    index,
-                    raid.level,
-                    raid.raidName,
-                    raid.difficulty,
-                    raid.gate,
-                    raid.reward,
-                    raid.resetType,
-                    0
-                ]
-            );
-            index++;
-        }
 
-        await db.execute(
-            `UPDATE table_versions SET version = ${DEFAULT_RAIDS_VERSION}, updatedAt = CURRENT_TIMESTAMP WHERE tableName = 'default_raids'`
-        );
-
-        await db.execute("COMMIT;");
-
-        console.log(
-            "%cTransaction committed successfully.",
-            "color:white; font-style:bold; background-color:green; padding:3px; border-radius:4px; font-size:12px;"
-        );
-    } catch (error) {
-        await db.execute("ROLLBACK;"); // ! 오류가 발생하면 롤백
-        console.error("Transaction failed and rolled back:", error);
-    } finally {
-        // await db.close();
-        console.log("Default Raids Table Update Finish!");
-    }
+    const imported = await invoke("import_legacy_homework", { homework: legacy });
+    console.log(`이전 버전 숙제표 가져오기 ${imported ? "완료" : "거부됨"}`);
 }
@@ -70,6 +70,7 @@ export interface RaidType {
     gate: string | null;
     reward: number | null;
     resetType: number;
+    retired?: boolean;
     source?: "default" | "group";
 }
 
@@ -94,21 +95,126 @@ export interface LiveType {
     resetType: number;
 }
 
+// * Group Raid Type (group_raids + group_raids_map)
+export interface RaidGroupType extends RaidType {
+    raidIds: number[];
+}
+
+// * Backend Homework State Type
+export interface HomeworkType {
+    characters: CharacterType[];
+    default_raids: RaidType[];
+    default_raids_version: number;
+    group_raids: RaidGroupType[];
+    live_raids: LiveType[];
+    legacy_imported: boolean; // ? 이전 버전 SQLite(live.db) 숙제표를 가져왔는지 여부
+}
+
+// * Raid Catalogue Sync Result Type
+export interface CatalogueDiff {
+    fromVersion: number;
+    toVersion: number;
+    added: number[];
+    updated: number[];
+    retired: number[];
+}
+
+// * Weekly Reset Info Type (unix sec)
+export interface ResetInfo {
+    last_reset_at: number;
+    next_reset_at: number;
+    next_kind: "week" | "twoWeek";
+}
+
+// * Weekly Reset Event Type (homework://reset 이벤트 payload)
+export interface ResetEvent {
+    kind: ResetInfo["next_kind"];
+    reset_at: number; // unix sec
+}
+
+// * Weekly Gold Income Types
+export interface CharacterIncome {
+    charId: number;
+    expected: number;
+    earned: number;
+    remaining: number;
+}
+
+export interface IncomeProjection {
+    expected: number;
+    earned: number;
+    remaining: number;
+    characters: CharacterIncome[];
+}
+
+// * Gold Ledger Types
+export type GoldSource = "manual" | "raidClear" | "reset";
+
+export interface GoldEntry {
+    id: number;
+    timestamp: number;
+    delta: number;
+    source: GoldSource;
+    charId: number | null;
+}
+
+export interface CharacterGold {
+    charId: number | null;
+    total: number;
+}
+
 // * Union Type
 export interface ExtendsRaidType extends RaidType, CharacterType, LiveType {
     count?: any;
 }
 
-// * DB Table Version Type
-export interface TableVersionType {
-    tableName: string;
-    version: number;
-    updatedAt: Date;
+// * 게임 창 이름 비교 방식
+export type TitleMatchMode = "exact" | "prefix" | "contains" | "regex" | "process";
+
+// * 비교 방식에 일치하는 창 Type
+export interface WindowMatch {
+    id: number;
+    title: string;
+    process: string | null; // ? 실행 파일 이름 (process 모드일 때만)
+}
+
+// * 감지된 게임 클라이언트 창 Type
+export interface GameWindow {
+    window_id: number;
+    pid: number;
+    process: string; // ? 실행 파일 이름
+    title: string;
+}
+
+// * 도킹된 가장자리 (모서리는 두 가장자리에 함께 도킹된 상태)
+export type DockPosition =
+    | "floating"
+    | "left"
+    | "right"
+    | "top"
+    | "bottom"
+    | "top_left"
+    | "top_right"
+    | "bottom_left"
+    | "bottom_right";
+
+// * Window Docking 상태 (dock://changed 이벤트 payload)
+export interface DockState {
+    position: DockPosition;
+    monitor_id: string | null; // ? 자유 이동 상태면 null
+}
+
+// + 도킹 위치에 맞는 레이아웃 방향 (좌/우 가장자리는 세로, 위/아래 가장자리는 가로, 모서리/자유 이동은 기본)
+export function dockLayout(position: DockPosition): "vertical" | "horizontal" | "default" {
+    if (position === "left" || position === "right") return "vertical";
+    if (position === "top" || position === "bottom") return "horizontal";
+    return "default";
 }
 
 // * Program User Settings Type
 export class UserSettingsType {
     update_check_enabled: boolean;
+    update_check_interval: number; // min
     theme: string;
     class_image: boolean;
     folded_opacity_enabled: boolean;
@@ -119,29 +225,54 @@ export class UserSettingsType {
     auto_focus_enabled: boolean;
     auto_focus_settings: {
         game_title: string;
+        match_mode: TitleMatchMode;
         shift_idle_time: number;
     };
     focus_border_enabled: boolean;
+    dock_settings: {
+        snap_distance: number; // px
+        undock_distance: number; // px
+        edges: { left: boolean; right: boolean; top: boolean; bottom: boolean };
+        auto_hide: boolean; // ? 대기시간(hide_delay) 후 가장자리 밖으로 숨김
+        hide_delay: number; // sec
+        reveal_strip: number; // px
+    };
+    anchor_enabled: boolean; // ? 게임 창에 붙여서 따라가기
     default_tab: string;
     close_button_behavior: string;
     auto_detect_title: boolean;
+    update_channel: "stable" | "beta";
+    update_mirror_url: string | null;
 
     // ? 타입 기본값 설정
     constructor(initialSettings?: Partial<UserSettingsType>) {
         this.update_check_enabled = initialSettings?.update_check_enabled ?? true;
+        this.update_check_interval = initialSettings?.update_check_interval ?? 360;
         this.theme = initialSettings?.theme ?? "light";
         this.class_image = initialSettings?.class_image ?? true;
         this.folded_opacity_enabled = initialSettings?.folded_opacity_enabled ?? true;
         this.folded_settings = initialSettings?.folded_settings ?? { opacity: 60, idle_time: 10 };
         this.auto_focus_enabled = initialSettings?.auto_focus_enabled ?? true;
         this.auto_focus_settings = initialSettings?.auto_focus_settings ?? {
-            game_title: "LOST ARK (64-bit, DX11) v.3.5.7.1",
+            game_title: "LOST ARK (",
+            match_mode: "prefix",
             shift_idle_time: 1
         };
         this.focus_border_enabled = initialSettings?.focus_border_enabled ?? true;
+        this.dock_settings = initialSettings?.dock_settings ?? {
+            snap_distance: 5,
+            undock_distance: 20,
+            edges: { left: true, right: true, top: true, bottom: true },
+            auto_hide: false,
+            hide_delay: 3,
+            reveal_strip: 4
+        };
+        this.anchor_enabled = initialSettings?.anchor_enabled ?? false;
         this.default_tab = initialSettings?.default_tab ?? "Tab1";
         this.close_button_behavior = initialSettings?.close_button_behavior ?? "tray";
         this.auto_detect_title = initialSettings?.auto_detect_title ?? true;
+        this.update_channel = initialSettings?.update_channel ?? "stable";
+        this.update_mirror_url = initialSettings?.update_mirror_url ?? null;
     }
 
     // ? defaultSettings, currentSettings 두개의 User Setting 객체 비교
@@ -182,6 +313,27 @@ function isObject(value: unknown): value is object {
     return typeof value === "object" && value !== null;
 }
 
+// + 업데이트 후 변경 사항 (get_whats_new 반환 타입)
+export interface ChangelogEntry {
+    version: string;
+    pub_date: string;
+    notes: string;
+}
+
+export interface WhatsNew {
+    previous_version: string;
+    current_version: string;
+    releases: ChangelogEntry[]; // 최신 버전부터
+}
+
+// + 업데이트 다운로드 진행률 (update://progress 이벤트)
+export interface UpdateProgress {
+    file: string;
+    downloaded: number;
+    total: number | null;
+    speed: number; // bytes/sec
+}
+
 // + 업데이트 확인 반환 타입
 export interface UpdateCheckResult {
     should_update: boolean;
@@ -192,10 +344,12 @@ export interface UpdateCheckResult {
         bootstrapper: {
             url: string;
             sha256: string;
+            signature: string; // minisign 서명
         };
         release: {
             url: string;
             sha256: string;
+            signature: string; // minisign 서명
         };
     };
 }
@@ -11,18 +11,7 @@ async function setWindowPosition() {
 
     // ? 창의 현재 위치를 가져옵니다.
     const { x, y } = await appWindow.outerPosition();
-    debounceInvoke(x, y);
+    invoke("set_position", { newPosition: { x, y } }); // * Rust 단에서 position 위치 저장 (저장은 Rust 워커가 모아서 처리)
 }
 
-let timeout: number | undefined;
-
-// + Debounce Invoke 실행
-const debounceInvoke = (x: number, y: number) => {
-    clearTimeout(timeout);
-    timeout = setTimeout(() => {
-        invoke("set_position", { newPosition: { x, y } }); // * Rust 단에서 position 위치 저장
-        console.log("Window 포지션 설정됨");
-    }, 200);
-};
-
 export default setWindowPosition;
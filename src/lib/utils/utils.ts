@@ -124,3 +124,24 @@ export async function checkUpdateUnified(showDialog: boolean, forceRefresh = fal
         invoke("resume_auto_focus"); // ? 자동 포커스 다시 활성화
     }
 }
+
+// + 업데이트 후 처음 실행했을 때 건너뛴 버전들의 변경 사항 표시
+export async function showWhatsNew() {
+    try {
+        const whatsNew = await invoke("get_whats_new");
+        if (!whatsNew) return;
+
+        if (whatsNew.releases.length > 0) {
+            const notes = whatsNew.releases
+                .map((release) => `[v${release.version}]\n${release.notes}`)
+                .join("\n\n");
+            await message(`v${whatsNew.previous_version} → v${whatsNew.current_version}\n\n${notes}`, {
+                title: "업데이트 변경 사항",
+                kind: "info"
+            });
+        }
+        await invoke("mark_whats_new_seen");
+    } catch (err) {
+        console.error("변경 사항 확인 실패:", err);
+    }
+}
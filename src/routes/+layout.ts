@@ -6,11 +6,11 @@ export const ssr = false;
 
 import type { LayoutLoad } from "./$types";
 
-import { initializerDB } from "$lib/db";
+import { importLegacyHomework } from "$lib/db";
 
 export const load: LayoutLoad = async () => {
-    // DB 초기화가 끝날 때까지 기다림
-    await initializerDB();
+    // 이전 버전 숙제표 가져오기가 끝날 때까지 기다림
+    await importLegacyHomework();
 
     return {};
 };
@@ -1,13 +1,10 @@
-import Database from "@tauri-apps/plugin-sql";
 import { cloneDeep } from "lodash-es";
 import { writable } from "svelte/store";
 
-import { initializerDB, updateDefaultRaidsTable } from "$lib/db";
-import { DEFAULT_RAIDS_VERSION } from "$lib/db/schema";
-import { UserSettingsType, type ExtendsRaidType, type TableVersionType } from "$lib/types";
+import { UserSettingsType, type DockState, type ExtendsRaidType, type HomeworkType, type RaidType } from "$lib/types";
 import { invoke } from "$lib/utils/invoke";
 
-// ? 프로그램 모드에 따라서 사용 DB 달라짐
+// ? 프로그램 모드에 따라서 사용 DB 달라짐 (이전 버전 숙제표 가져오기용)
 export const liveDbName = import.meta.env.PROD ? "live" : "live_dev";
 if (import.meta.env.PROD) {
     console.log(
@@ -23,7 +20,6 @@ if (import.meta.env.PROD) {
 
 // * Define the shape of the store's data
 interface AppState {
-    liveDbName: string;
     raidsTable: ExtendsRaidType[];
     gold: number;
     totalReward: number;
@@ -34,11 +30,11 @@ interface AppState {
     workResetModalOpen: boolean;
     groupEditModalOpen: boolean;
     updateExists: boolean;
+    dockState: DockState;
 }
 
 // * Default initial state
 const initialState: AppState = {
-    liveDbName,
     raidsTable: [],
     gold: 0,
     totalReward: 0,
@@ -48,105 +44,55 @@ const initialState: AppState = {
     userSettings: new UserSettingsType(),
     workResetModalOpen: false,
     groupEditModalOpen: false,
-    updateExists: false
+    updateExists: false,
+    dockState: { position: "floating", monitor_id: null }
 };
 
 // + Create a writable store with the initial state
 export const appStore = writable<AppState>(initialState);
 
+// + 숙제표 레이드 찾기 (raidType, raidId 기준)
+export const findHomeworkRaid = (
+    homework: HomeworkType,
+    raidType: "default" | "group" | undefined,
+    raidId: number
+): RaidType | undefined => {
+    if (raidType === "group") return homework.group_raids.find((raid) => raid.raidId === raidId);
+    return homework.default_raids.find((raid) => raid.raidId === raidId);
+};
+
+// + live_raids 기준으로 캐릭터, 레이드 정보를 합친 숙제표 (캐릭터 우선순위, id 순)
+export const buildRaidsTable = (homework: HomeworkType): ExtendsRaidType[] => {
+    const raidsTable: ExtendsRaidType[] = [];
+
+    for (const live of homework.live_raids) {
+        const character = homework.characters.find((char) => char.charId === live.charId);
+        const raid = findHomeworkRaid(homework, live.raidType, live.raidId);
+        if (!character || !raid) continue;
+
+        // ? 정렬은 캐릭터 우선순위, 초기화 주기는 레이드 기준 (기존 SQL Join과 동일)
+        raidsTable.push({ ...live, ...character, ...raid, priority: character.priority });
+    }
+
+    return raidsTable.sort((a, b) => (a.priority ?? 0) - (b.priority ?? 0) || a.id! - b.id!);
+};
+
 // + Optional: Create helper functions for updating state
-export const loadLiveDB = async (skip?: boolean) => {
+export const loadLiveDB = async () => {
     console.log(
         "%cReload Live DB",
         "color:white; font-style:bold; background-color:limeGreen; padding:3px; border-radius:4px; font-size:12px;"
     );
 
-    // if (!skip) await initializerDB(); // * DataBase Initializer
-
     let raidsTable: ExtendsRaidType[] = [];
 
-    const db = await Database.load(`sqlite:${liveDbName}.db`);
-
-    // * Default Raid Table 버전 확인
-    if (!skip) {
-        try {
-            const tableVersions: TableVersionType[] = await db.select(
-                "SELECT * FROM table_versions WHERE tableName = 'default_raids'"
-            );
-            console.log(`Current Version: ${tableVersions[0].version}\nProgram Version: ${DEFAULT_RAIDS_VERSION}`);
-
-            if (tableVersions[0].version < DEFAULT_RAIDS_VERSION) {
-                // ? Default Raid Version이 현재 DB에 저장된 데이터보다 버전이 높을때
-                console.log("Default Raids Table 버전을 업데이트 합니다.");
-                await updateDefaultRaidsTable(db);
-            } else {
-                // ? Default Raid Version이 현재 DB에 저장된 데이터랑 같거나 낮을때
-                console.log("Default Raids Table 버전을 업데이트 하지 않음.");
-            }
-        } catch (error) {
-            console.error("Transaction failed:", error);
-        }
-    }
-
     try {
-        // ? live_raids 테이블에 등록되어있는 숙제표 기준으로 Table Join
-        raidsTable = await db.select(`
-            SELECT
-                live_raids.id,
-                characters.priority,
-                live_raids.charId,
-                characters.charName,
-                characters.class,
-                characters.color,
-                live_raids.raidType,
-                live_raids.complete,
-                live_raids.swap,
-                COALESCE(dr.raidId, cr.raidId) AS raidId,
-                COALESCE(dr.level, cr.level) AS level,
-                COALESCE(dr.raidName, cr.raidName) AS raidName,
-                COALESCE(dr.difficulty, cr.difficulty) AS difficulty,
-                COALESCE(dr.gate, cr.gate) AS gate,
-                
-                -- Reward 처리 부분
-                COALESCE(dr.reward, cr_agg.reward_sum) AS reward,
-                
-                COALESCE(dr.resetType, cr.resetType) AS resetType
-
-            FROM live_raids
-            LEFT JOIN characters ON live_raids.charId = characters.charId
-
-            -- 기존 default_raids
-            LEFT JOIN default_raids AS dr ON live_raids.raidId = dr.raidId
-
-            -- 기존 group_raids
-            LEFT JOIN group_raids AS cr ON live_raids.raidId = cr.raidId
-
-            -- 그룹 레이드 reward 합산용 서브쿼리
-            LEFT JOIN (
-                SELECT
-                    cr_inner.raidId AS groupRaidId,
-                    COALESCE(SUM(dr_inner.reward), 0) AS reward_sum
-                FROM group_raids AS cr_inner
-                LEFT JOIN group_raids_map AS crm ON cr_inner.raidId = crm.groupId
-                LEFT JOIN default_raids AS dr_inner ON crm.raidId = dr_inner.raidId
-                GROUP BY cr_inner.raidId
-            ) AS cr_agg ON cr.raidId = cr_agg.groupRaidId
-
-            ORDER BY characters.priority, live_raids.id;
-        `);
+        // ? 숙제표는 Rust Backend 상태(state::homework)에서 관리
+        raidsTable = buildRaidsTable(await invoke("get_homework"));
     } catch (error) {
-        console.error("Transaction failed:", error);
-    } finally {
-        await db.close();
+        console.error("Load homework failed:", error);
     }
 
-    // ? complete 값이 1이면 true로 타입 변환
-    raidsTable.map((arr) => {
-        arr.complete = Number(arr.complete) === 1 ? true : false;
-    });
-
-    // console.log(raidsTable);
-
     // todo 이 계산식 부분만 따로 구현하면 될듯함
     let totalReward: number = 0; // * 총합 골드 보상
     let totalRaids: number = 0; // * 레이드 총 갯수
@@ -157,8 +103,8 @@ export const loadLiveDB = async (skip?: boolean) => {
     raidsTable.map((arr) => {
         totalReward += arr.reward!;
         totalRaids++;
-        remainingReward += Number(arr.complete) === 0 ? arr.reward! : 0;
-        remainingRaids += Number(arr.complete) === 0 ? 1 : 0;
+        remainingReward += !arr.complete ? arr.reward! : 0;
+        remainingRaids += !arr.complete ? 1 : 0;
     });
 
     appStore.update((state) => ({
@@ -229,19 +175,3 @@ export const setUserSettings = async (userSettings: UserSettingsType) => {
         userSettings: cloneDeep(userSettings)
     }));
 };
-
-export const setDetectTitle = async (title: string) => {
-    await invoke("set_game_title", { title });
-
-    // ? 기존 구조 유지하면서 game_title 필드만 수정
-    appStore.update((state) => ({
-        ...state,
-        userSettings: {
-            ...state.userSettings,
-            auto_focus_settings: {
-                ...state.userSettings.auto_focus_settings,
-                game_title: title
-            }
-        }
-    }));
-};
@@ -1,4 +1,23 @@
-import type { SystemSounds, UpdateCheckResult, UserSettingsType } from "$lib/types";
+import type {
+    CatalogueDiff,
+    CharacterGold,
+    CharacterType,
+    DockState,
+    GameWindow,
+    GoldEntry,
+    GoldSource,
+    HomeworkType,
+    IncomeProjection,
+    LiveType,
+    ResetInfo,
+    RaidGroupType,
+    SystemSounds,
+    UpdateCheckResult,
+    TitleMatchMode,
+    UserSettingsType,
+    WhatsNew,
+    WindowMatch
+} from "$lib/types";
 
 // * invoke 커맨드 타입 지정
 export interface TauriCommands {
@@ -10,32 +29,77 @@ export interface TauriCommands {
     };
     play_system_sound: { sound: keyof typeof SystemSounds } | undefined;
     get_gold: undefined; // ! Return: number
-    set_gold: { value: number };
-    increment_gold: { value: number };
-    decrement_gold: { value: number };
+    set_gold: { value: number; source: GoldSource };
+    increment_gold: { value: number; source?: GoldSource; charId?: number };
+    decrement_gold: { value: number; source?: GoldSource; charId?: number };
+    get_gold_history: { limit?: number } | undefined; // ! Return: GoldEntry[]
+    get_weekly_gold_total: undefined; // ! Return: number
+    get_gold_by_character: undefined; // ! Return: CharacterGold[]
+    undo_last_gold_entry: undefined; // ! Return: number
     get_user_settings: undefined; // ! Return: UserSettingsType
     set_user_settings: { settings: UserSettingsType };
     get_position: undefined; // ! Return: { x: number, y: number }
     set_position: { newPosition: { x: number; y: number } };
-    find_window_by_title: { target: string }; // ! Return: string
+    get_dock_state: undefined; // ! Return: DockState
+    find_window_by_title: { target: string; mode?: TitleMatchMode }; // ! Return: string | null
+    preview_title_matches: { pattern: string; mode: TitleMatchMode }; // ! Return: WindowMatch[]
+    get_detected_game: undefined; // ! Return: GameWindow | null
     pause_auto_focus: undefined;
     resume_auto_focus: undefined;
+    suspend_mouse_tracking: undefined;
+    resume_mouse_tracking: undefined;
     get_os_info; // ! Return: [string, string]
     get_update_check_result: { forceRefresh: boolean }; // ! Return: UpdateCheckResult
     run_update_with_info: { info: UpdateCheckResult["info"] };
+    check_raid_catalogue_update: undefined; // ! Return: CatalogueDiff | null
+    get_whats_new: undefined; // ! Return: WhatsNew | null
+    mark_whats_new_seen: undefined;
     get_default_tab; // ! Return: string
     exit_app;
-    set_game_title: { title: string };
+    get_homework: undefined; // ! Return: HomeworkType
+    get_last_catalogue_sync: undefined; // ! Return: CatalogueDiff | null
+    upsert_character: { character: CharacterType };
+    remove_character: { charId: number }; // ! Return: boolean
+    set_characters: { characters: CharacterType[] };
+    upsert_group_raid: { group: RaidGroupType };
+    remove_group_raid: { raidId: number }; // ! Return: boolean
+    set_group_raids: { groups: RaidGroupType[] };
+    set_live_raids: { charId: number; raids: LiveType[] };
+    replace_live_raids: { raids: LiveType[] };
+    set_live_raid_complete: { id: number; complete: boolean };
+    import_legacy_homework: { homework: HomeworkType }; // ! Return: boolean
+    is_legacy_homework_imported: undefined; // ! Return: boolean
+    get_reset_info: undefined; // ! Return: ResetInfo
+    get_weekly_income: undefined; // ! Return: IncomeProjection
+    reset_homework: { kind: ResetInfo["next_kind"] };
+    set_biweekly_reset_week: { isBiweekly: boolean };
 }
 
 // prettier-ignore
 // * invoke 반환값 타입 지정
 export type TauriCommandResponse<K extends keyof TauriCommands>
     = K extends "get_gold" ? Promise<number>
+    : K extends "get_gold_history" ? Promise<GoldEntry[]>
+    : K extends "get_weekly_gold_total" ? Promise<number>
+    : K extends "get_gold_by_character" ? Promise<CharacterGold[]>
+    : K extends "undo_last_gold_entry" ? Promise<number>
     : K extends "get_user_settings" ? Promise<UserSettingsType>
     : K extends "get_position" ? Promise<{ x: number; y: number }>
-    : K extends "find_window_by_title" ? Promise<string>
+    : K extends "get_dock_state" ? Promise<DockState>
+    : K extends "find_window_by_title" ? Promise<string | null>
+    : K extends "preview_title_matches" ? Promise<WindowMatch[]>
+    : K extends "get_detected_game" ? Promise<GameWindow | null>
     : K extends "get_os_info" ? Promise<[string, string]>
     : K extends "get_update_check_result" ? Promise<UpdateCheckResult>
     : K extends "get_default_tab" ? Promise<string>
+    : K extends "get_homework" ? Promise<HomeworkType>
+    : K extends "get_last_catalogue_sync" ? Promise<CatalogueDiff | null>
+    : K extends "check_raid_catalogue_update" ? Promise<CatalogueDiff | null>
+    : K extends "get_whats_new" ? Promise<WhatsNew | null>
+    : K extends "remove_character" ? Promise<boolean>
+    : K extends "remove_group_raid" ? Promise<boolean>
+    : K extends "import_legacy_homework" ? Promise<boolean>
+    : K extends "is_legacy_homework_imported" ? Promise<boolean>
+    : K extends "get_reset_info" ? Promise<ResetInfo>
+    : K extends "get_weekly_income" ? Promise<IncomeProjection>
     : Promise<any>; // ? 기본 반환값